@@ -1,4 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, Bencher, Criterion};
+use graph_algo_ptas::data_structure::vec_dcel::VecDcel;
 use graph_algo_ptas::embedding::index::Embedding;
 use graph_algo_ptas::embedding::maximal_planar::index::MaximalPlanar;
 use graph_algo_ptas::generation::planar::generate;
@@ -11,11 +12,28 @@ fn bench_embedding(n: usize) -> impl Fn(&mut Bencher) {
     }
 }
 
+fn bench_vec_dcel_embedding(n: usize) -> impl Fn(&mut Bencher) {
+    move |b| {
+        let graph = generate(n, Some(44)).to_pet_graph();
+        b.iter(|| {
+            let dcel: VecDcel = MaximalPlanar::embed_into(black_box(graph.clone()));
+            dcel
+        })
+    }
+}
+
 fn embedding_benchmark(c: &mut Criterion) {
     let mut g = c.benchmark_group("MaximalPlanar embedding");
     g.bench_function("MaximalPlanar embedding (|G|=10)", bench_embedding(10));
     g.bench_function("MaximalPlanar embedding (|G|=100)", bench_embedding(100));
     g.bench_function("MaximalPlanar embedding (|G|=1000)", bench_embedding(1000));
+    g.bench_function("VecDcel embedding (|G|=10)", bench_vec_dcel_embedding(10));
+    g.bench_function("VecDcel embedding (|G|=100)", bench_vec_dcel_embedding(100));
+    g.bench_function(
+        "VecDcel embedding (|G|=1000)",
+        bench_vec_dcel_embedding(1000),
+    );
+    g.finish();
 }
 
 criterion_group! {
@@ -1,6 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Bencher, Criterion};
 use graph_algo_ptas::generation::erdos_renyi::generate_petgraph;
-use graph_algo_ptas::generation::planar::generate;
+use graph_algo_ptas::generation::planar::{generate, generate_edges};
 
 fn bench_planar_generation(n: usize) -> impl Fn(&mut Bencher) {
     move |b| {
@@ -10,6 +10,14 @@ fn bench_planar_generation(n: usize) -> impl Fn(&mut Bencher) {
     }
 }
 
+fn bench_planar_edge_generation(n: usize) -> impl Fn(&mut Bencher) {
+    move |b| {
+        b.iter(|| {
+            generate_edges(black_box(n), black_box(Some(44))).count();
+        })
+    }
+}
+
 fn bench_er_generation(n: usize) -> impl Fn(&mut Bencher) {
     move |b| {
         b.iter(|| {
@@ -26,6 +34,10 @@ fn generation_benchmark(c: &mut Criterion) {
         "Planar Generation (|G|=1000)",
         bench_planar_generation(1000),
     );
+    pgg.bench_function(
+        "Planar Edge Generation (|G|=1000000)",
+        bench_planar_edge_generation(1_000_000),
+    );
     drop(pgg);
 
     let mut erg = c.benchmark_group("Erdos Renyi Generation");
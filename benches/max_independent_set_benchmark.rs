@@ -1,6 +1,8 @@
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
-use graph_algo_ptas::algorithm::dynamic_programming::solve::{dp_solve, DpProblem};
-use graph_algo_ptas::algorithm::ptas::ptas;
+use graph_algo_ptas::algorithm::dynamic_programming::solve::{dp_solve, DpProblem, TdStrategy};
+use graph_algo_ptas::algorithm::ptas::{ptas, ptas_with_strategy};
+use graph_algo_ptas::algorithm::separator_ptas::separator_ptas;
+use graph_algo_ptas::embedding::{index::Embedding, maximal_planar::index::MaximalPlanar};
 use graph_algo_ptas::generation::planar::generate;
 
 fn max_independent_set_ptas_1(c: &mut Criterion) {
@@ -43,6 +45,47 @@ fn max_independent_set_ptas_2(c: &mut Criterion) {
     group.finish();
 }
 
+fn max_independent_set_separator_ptas(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Separator PTAS | eps=1/2 | Maximum Independent Set");
+    group.sample_size(10);
+    let mut i = 0;
+
+    for n in [500, 1000, 2000, 4000, 8000] {
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, &n| {
+            b.iter(|| {
+                i += 1;
+                separator_ptas(
+                    &MaximalPlanar::embed(generate(n as usize, Some(i)).to_pet_graph()),
+                    &DpProblem::max_independent_set(),
+                    1.0 / 2.0,
+                )
+            });
+        });
+    }
+    group.finish();
+}
+
+fn max_independent_set_ptas_strategies(c: &mut Criterion) {
+    let mut group = c.benchmark_group("PTAS | eps=1/2 | Maximum Independent Set | Min-Fill");
+    group.sample_size(10);
+    let mut i = 0;
+
+    for n in [500, 1000, 2000, 4000, 8000] {
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, &n| {
+            b.iter(|| {
+                i += 1;
+                ptas_with_strategy(
+                    &generate(n as usize, Some(i)).to_pet_graph(),
+                    &DpProblem::max_independent_set(),
+                    1.0 / 2.0,
+                    &TdStrategy::MinFill,
+                )
+            });
+        });
+    }
+    group.finish();
+}
+
 fn max_independent_set_dp(c: &mut Criterion) {
     let mut group = c.benchmark_group("DP | Maximum Independent Set");
     group.sample_size(10);
@@ -67,6 +110,8 @@ criterion_group!(
     benches,
     max_independent_set_ptas_1,
     max_independent_set_ptas_2,
+    max_independent_set_separator_ptas,
+    max_independent_set_ptas_strategies,
     max_independent_set_dp
 );
 criterion_main!(benches);
@@ -0,0 +1,407 @@
+//! Contains distance oracles and a diameter approximation for planar graphs.
+//!
+//! [DistanceOracle] answers distance queries with stretch `1 + eps`. The graph is split
+//! recursively by fundamental cycles of bfs trees, which consist of two shortest paths. Every
+//! shortest path between two vertices meets one of these paths in the deepest piece containing
+//! it, so it suffices to store for every vertex its distances to a few portals on the paths of
+//! the pieces containing it. For every scale `2^i` the portals are spaced `eps * 2^(i - 1)`
+//! apart and each vertex stores the portals within distance `2^(i + 1)`, which are `O(1 / eps)`
+//! per path and scale. So the labels have size `O(log^2 n / eps)`, the preprocessing takes
+//! `O(n log^2 n / eps)` time and a query takes `O(log^2 n / eps)` time by merging two labels,
+//! so queries are not answered in constant time.
+//!
+//! [LandmarkDistances] stores the distances of every vertex to `k` landmarks. The bounds
+//! combine the distances of both vertices to the landmarks and take `O(k)` time. The
+//! preprocessing takes `O(k * n)` time. The bounds are exact if one of the vertices is
+//! a landmark, but there is no guarantee on their quality for other vertices.
+//!
+//! ```rust
+//! use graph_algo_ptas::generation::planar::generate;
+//! use graph_algo_ptas::embedding::{index::Embedding, maximal_planar::index::MaximalPlanar};
+//! use graph_algo_ptas::algorithm::distance::{DistanceOracle, LandmarkDistances};
+//! use graph_algo_ptas::data_structure::graph_dcel::GraphDCEL;
+//!
+//! let graph = MaximalPlanar::embed(generate(100, Some(1)).to_pet_graph());
+//! let u = graph.get_vertexes().next().unwrap();
+//! let v = graph.get_vertexes().last().unwrap();
+//!
+//! let oracle = DistanceOracle::compute(&graph, 0.5);
+//! let distance = oracle.distance(&u, &v).unwrap();
+//!
+//! let landmarks = LandmarkDistances::compute(&graph, 4);
+//! assert!(landmarks.lower_bound(&u, &v) <= distance);
+//! assert!(landmarks.lower_bound(&u, &v) <= landmarks.upper_bound(&u, &v));
+//! ```
+
+use crate::algorithm::{
+    leveling::Leveling,
+    separator::{rotation_system, shortest_path_separator},
+    spantree::Span,
+};
+use crate::data_structure::graph_dcel::{Dart, Face, GraphDCEL, Vertex};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// The structure answering distance queries with stretch `1 + eps`
+pub struct DistanceOracle<T> {
+    eps: f64,
+    // the ids of the portals and the distances to them for every vertex, sorted by the ids
+    labels: HashMap<T, Vec<(usize, usize)>>,
+    portal_count: usize,
+}
+
+impl<V: Vertex + Eq + Hash + Clone> DistanceOracle<V> {
+    /// Returns the distance oracle of the embedded graph with stretch `1 + eps`.
+    ///
+    /// `eps` has to be in `(0, 1]`.
+    pub fn compute<
+        D: Dart + Eq + Clone,
+        F: Face + Eq + Hash + Clone,
+        VI: Iterator<Item = V>,
+        DI: Iterator<Item = D>,
+        FI: Iterator<Item = F>,
+    >(
+        g: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+        eps: f64,
+    ) -> Self {
+        assert!(eps > 0.0 && eps <= 1.0, "eps has to be in (0, 1]");
+        let rotation = rotation_system(g);
+        let mut oracle = DistanceOracle {
+            eps,
+            labels: g.get_vertexes().map(|v| (v, vec![])).collect(),
+            portal_count: 0,
+        };
+        let mut pieces = vec![g.get_vertexes().collect::<HashSet<_>>()];
+
+        // a piece is handled before the pieces inside it, so the labels are sorted by the ids
+        while let Some(piece) = pieces.pop() {
+            if piece.len() < 2 {
+                continue;
+            }
+
+            let (paths, separator) = shortest_path_separator(&rotation, &piece);
+
+            for path in paths {
+                oracle.add_portals(&rotation, &piece, &path);
+            }
+
+            pieces.push(separator.a);
+            pieces.push(separator.b);
+        }
+
+        oracle
+    }
+
+    /// Returns the distance of the two vertices up to a factor of `1 + eps`, which is at least
+    /// their distance. Returns `None` if the vertices are not connected.
+    pub fn distance(&self, u: &V, v: &V) -> Option<usize> {
+        if u == v {
+            return Some(0);
+        }
+
+        let (a, b) = (&self.labels[u], &self.labels[v]);
+        let (mut i, mut j) = (0, 0);
+        let mut distance = usize::MAX;
+
+        while i < a.len() && j < b.len() {
+            match a[i].0.cmp(&b[j].0) {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    distance = distance.min(a[i].1 + b[j].1);
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+
+        (distance < usize::MAX).then_some(distance)
+    }
+
+    /// Returns the number of portals stored for the vertex.
+    pub fn label_size(&self, v: &V) -> usize {
+        self.labels[v].len()
+    }
+
+    // Adds the portals of a shortest path of the piece. A shortest path of length at most
+    // `2^i` meeting the path at `p` passes within `eps * 2^(i - 2)` of a portal of scale
+    // `2^i` near `p`, which both ends store.
+    fn add_portals(&mut self, rotation: &HashMap<V, Vec<V>>, piece: &HashSet<V>, path: &[V]) {
+        let mut radius = vec![0; path.len()];
+        let mut scale = 1;
+
+        loop {
+            let spacing = ((self.eps * scale as f64 / 2.0) as usize).max(1);
+
+            for k in (0..path.len()).step_by(spacing).chain([path.len() - 1]) {
+                radius[k] = 2 * scale;
+            }
+
+            if scale >= piece.len() {
+                break;
+            }
+            scale *= 2;
+        }
+
+        for (portal, radius) in path.iter().zip(radius) {
+            if radius == 0 {
+                continue;
+            }
+
+            let id = self.portal_count;
+            self.portal_count += 1;
+            let mut distance = HashMap::from([(portal.clone(), 0)]);
+            let mut queue = VecDeque::from([portal.clone()]);
+
+            while let Some(v) = queue.pop_front() {
+                let d = distance[&v];
+                self.labels.get_mut(&v).unwrap().push((id, d));
+
+                if d == radius {
+                    continue;
+                }
+
+                for u in &rotation[&v] {
+                    if piece.contains(u) && !distance.contains_key(u) {
+                        distance.insert(u.clone(), d + 1);
+                        queue.push_back(u.clone());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The structure containing the distances of every vertex to a set of landmarks
+pub struct LandmarkDistances<T> {
+    /// The landmarks
+    pub landmarks: Vec<T>,
+    /// Maps every vertex to its distance for each landmark
+    pub distances: Vec<HashMap<T, usize>>,
+}
+
+impl<V: Vertex + Eq + Hash + Clone> LandmarkDistances<V> {
+    /// Returns the distances to up to `landmark_count` landmarks.
+    ///
+    /// The landmarks are chosen by farthest point sampling. The graph is expected to be connected.
+    pub fn compute<
+        D: Dart + Eq + Clone,
+        F: Face + Eq + Hash + Clone,
+        VI: Iterator<Item = V>,
+        DI: Iterator<Item = D>,
+        FI: Iterator<Item = F>,
+    >(
+        g: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+        landmark_count: usize,
+    ) -> Self {
+        assert!(landmark_count > 0);
+        let first = g.get_vertexes().next().unwrap();
+        let mut landmarks = vec![];
+        let mut distances: Vec<HashMap<V, usize>> = vec![];
+        let mut next = Some(first);
+
+        while let Some(landmark) = next {
+            let leveling = if g.vertex_count() > 1 {
+                Leveling::compute(Span::compute(g, landmark.clone()))
+            } else {
+                Leveling {
+                    levels: vec![[landmark.clone()].into()],
+                }
+            };
+            let mut distance = HashMap::new();
+
+            for (i, level) in leveling.levels.iter().enumerate() {
+                for v in level {
+                    distance.insert(v.clone(), i);
+                }
+            }
+
+            landmarks.push(landmark);
+            distances.push(distance);
+
+            next = if landmarks.len() < landmark_count {
+                g.get_vertexes()
+                    .map(|v| (distances.iter().map(|d| d[&v]).min().unwrap(), v))
+                    .filter(|(d, _)| *d > 0)
+                    .max_by_key(|(d, v)| (*d, v.get_id()))
+                    .map(|(_, v)| v)
+            } else {
+                None
+            };
+        }
+
+        LandmarkDistances {
+            landmarks,
+            distances,
+        }
+    }
+
+    /// Returns an upper bound for the distance of the two vertices.
+    ///
+    /// The result is exact if one of the vertices is a landmark.
+    pub fn upper_bound(&self, u: &V, v: &V) -> usize {
+        if u == v {
+            return 0;
+        }
+
+        self.distances.iter().map(|d| d[u] + d[v]).min().unwrap()
+    }
+
+    /// Returns a lower bound for the distance of the two vertices.
+    pub fn lower_bound(&self, u: &V, v: &V) -> usize {
+        self.distances
+            .iter()
+            .map(|d| d[u].abs_diff(d[v]))
+            .max()
+            .unwrap()
+    }
+
+    /// Returns a lower and an upper bound for the diameter of the graph.
+    ///
+    /// The upper bound is at most twice the lower bound.
+    pub fn diameter_bounds(&self) -> (usize, usize) {
+        let eccentricities = self.distances.iter().map(|d| *d.values().max().unwrap());
+
+        (
+            eccentricities.clone().max().unwrap(),
+            2 * eccentricities.min().unwrap(),
+        )
+    }
+}
+
+/// Returns an approximation of the diameter of the graph which is at least half the diameter.
+pub fn approximate_diameter<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    g: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+) -> usize {
+    LandmarkDistances::compute(g, 2).diameter_bounds().0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{approximate_diameter, DistanceOracle, LandmarkDistances};
+    use crate::data_structure::{graph_dcel::GraphDCEL, link_graph::LinkGraph};
+    use crate::embedding::{index::Embedding, maximal_planar::index::MaximalPlanar};
+    use crate::generation::{
+        families::grid,
+        planar::{generate, generate_with_edges},
+    };
+    use std::collections::{HashMap, VecDeque};
+
+    fn bfs(g: &LinkGraph, start: usize) -> HashMap<usize, usize> {
+        let mut distance = HashMap::from([(start, 0)]);
+        let mut queue = VecDeque::from([g.vertex_by_id(start).unwrap()]);
+
+        while let Some(v) = queue.pop_front() {
+            for u in g.neighbors(&v) {
+                if !distance.contains_key(&u.get_id()) {
+                    distance.insert(u.get_id(), distance[&v.get_id()] + 1);
+                    queue.push_back(u);
+                }
+            }
+        }
+
+        distance
+    }
+
+    #[test]
+    fn single_vertex() {
+        let mut lg = LinkGraph::new();
+        let lv = lg.new_vertex();
+        let landmarks = LandmarkDistances::compute(&lg, 3);
+
+        assert_eq!(landmarks.landmarks.len(), 1);
+        assert_eq!(landmarks.upper_bound(&lv, &lv), 0);
+        assert_eq!(landmarks.diameter_bounds(), (0, 0));
+    }
+
+    #[test]
+    fn bounds() {
+        for n in 4..40 {
+            let lg = MaximalPlanar::embed(generate(n, Some(n as u64)).to_pet_graph());
+            let landmarks = LandmarkDistances::compute(&lg, 3);
+            let mut diameter = 0;
+
+            for u in lg.get_vertexes() {
+                let distance = bfs(&lg, u.get_id());
+
+                for v in lg.get_vertexes() {
+                    let d = distance[&v.get_id()];
+                    diameter = diameter.max(d);
+                    assert!(landmarks.lower_bound(&u, &v) <= d);
+                    assert!(landmarks.upper_bound(&u, &v) >= d);
+
+                    if landmarks.landmarks.contains(&u) {
+                        assert_eq!(landmarks.upper_bound(&u, &v), d);
+                    }
+                }
+            }
+
+            let (lower, upper) = landmarks.diameter_bounds();
+            assert!(lower <= diameter && diameter <= upper);
+            assert!(2 * approximate_diameter(&lg) >= diameter);
+        }
+    }
+
+    fn assert_stretch(lg: &LinkGraph, eps: f64) {
+        let oracle = DistanceOracle::compute(lg, eps);
+
+        for u in lg.get_vertexes() {
+            let distance = bfs(lg, u.get_id());
+
+            for v in lg.get_vertexes() {
+                let d = distance[&v.get_id()];
+                let approximation = oracle.distance(&u, &v).unwrap();
+
+                assert!(d <= approximation);
+                assert!(approximation as f64 <= (1.0 + eps) * d as f64);
+            }
+        }
+    }
+
+    #[test]
+    fn oracle_isolated_vertices() {
+        let mut lg = LinkGraph::new();
+        let lv = lg.new_vertex();
+        let other = lg.new_vertex();
+        let oracle = DistanceOracle::compute(&lg, 0.5);
+
+        assert_eq!(oracle.distance(&lv, &lv), Some(0));
+        assert_eq!(oracle.distance(&lv, &other), None);
+        assert_eq!(oracle.label_size(&lv), 0);
+    }
+
+    #[test]
+    fn oracle_stretch() {
+        for n in (4..40).chain([150]) {
+            let lg = MaximalPlanar::embed(generate(n, Some(n as u64)).to_pet_graph());
+            assert_stretch(&lg, 0.5);
+
+            let lg = generate_with_edges(n, n + n / 2, Some(n as u64)).to_link_graph();
+            assert_stretch(&lg, 0.25);
+        }
+
+        for eps in [0.1, 1.0] {
+            assert_stretch(&grid(12, 12).to_link_graph(), eps);
+            assert_stretch(&generate_with_edges(200, 199, Some(1)).to_link_graph(), eps);
+        }
+    }
+
+    #[test]
+    fn oracle_label_size() {
+        let lg = grid(40, 40).to_link_graph();
+        let oracle = DistanceOracle::compute(&lg, 0.5);
+
+        // far below the n entries needed to store all distances
+        assert!(lg
+            .get_vertexes()
+            .all(|v| oracle.label_size(&v) < lg.vertex_count() / 2));
+    }
+}
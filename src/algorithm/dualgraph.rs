@@ -1,78 +1,62 @@
 //! Contains the dual_graph function
 use crate::algorithm::spantree::Span;
-use crate::data_structure::{
-    graph_dcel::GraphDCEL,
-    link_graph::{LinkDart, LinkFace, LinkGraphIter, LinkVertex},
-};
-use std::collections::{HashMap, HashSet};
+use crate::data_structure::graph_dcel::{Dart, Face, GraphDCEL, Vertex};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
 
-/// Returns the dual graph that doesn't cross the edges of the span (face tree)
-pub fn dual_graph(
-    g: &impl GraphDCEL<
-        LinkVertex,
-        LinkDart,
-        LinkFace,
-        LinkGraphIter<LinkVertex>,
-        LinkGraphIter<LinkDart>,
-        LinkGraphIter<LinkFace>,
-    >,
-    span: &Span<LinkVertex>,
-) -> HashMap<LinkFace, HashSet<LinkFace>> {
-    let mut result = HashMap::new();
-    let mut visited = HashSet::new();
-    if g.get_vertexes().count() <= 2 {
-        let outer_face = g.get_faces().next();
-        if let Some(face) = outer_face {
-            result.insert(face, Default::default());
-        }
-        return result;
-    }
-    for face in g.get_faces() {
-        visited.insert(face.clone());
-        let first = g.dart_face(&face);
-        let mut current_dart = first.clone();
-        loop {
-            let next_dart = g.next(&current_dart);
-            if first == next_dart {
-                break;
-            }
-            let next_face = g.face(&g.twin(&current_dart));
+/// Returns the dual graph that doesn't cross the edges of the span (face tree).
+///
+/// The face tree is rooted at the face of the first dart of the graph and maps every face
+/// to its children. Every dart of a face is crossed, including the one closing its cycle.
+pub fn dual_graph<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    g: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+    span: &Span<V>,
+) -> HashMap<F, HashSet<F>> {
+    let root = g.face(&g.get_darts().next().unwrap());
+    let mut result = HashMap::from([(root.clone(), HashSet::new())]);
+    let mut visited = HashSet::from([root.clone()]);
+    let mut queue = VecDeque::from([root]);
 
-            if visited.insert(next_face.clone())
-                && (span.upwards.get(&g.dart_target(&current_dart))
-                    == Some(&g.dart_target(&g.twin(&current_dart)))
-                    || span.upwards.get(&g.dart_target(&g.twin(&current_dart)))
-                        == Some(&g.dart_target(&current_dart)))
+    while let Some(face) = queue.pop_front() {
+        for current_dart in g.face_darts(&face) {
+            let twin = g.twin(&current_dart);
+            let (u, v) = (g.dart_target(&current_dart), g.dart_target(&twin));
+            let next_face = g.face(&twin);
+
+            if span.upwards.get(&u) != Some(&v)
+                && span.upwards.get(&v) != Some(&u)
+                && visited.insert(next_face.clone())
             {
-                match result.get_mut(&face) {
-                    None => {
-                        let mut set = HashSet::new();
-                        set.insert(next_face.clone());
-                        result.insert(face.clone(), set);
-                    }
-                    Some(set) => {
-                        set.insert(next_face.clone());
-                    }
-                }
+                result
+                    .entry(face.clone())
+                    .or_insert_with(HashSet::new)
+                    .insert(next_face.clone());
+                queue.push_back(next_face);
             }
-            current_dart = next_dart;
         }
     }
     result
 }
 
 #[allow(dead_code)]
-fn dart_as_tuple(
-    g: &impl GraphDCEL<
-        LinkVertex,
-        LinkDart,
-        LinkFace,
-        LinkGraphIter<LinkVertex>,
-        LinkGraphIter<LinkDart>,
-        LinkGraphIter<LinkFace>,
-    >,
-    d: &LinkDart,
-) -> (LinkVertex, LinkVertex) {
+fn dart_as_tuple<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    g: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+    d: &D,
+) -> (V, V) {
     (g.dart_target(d), g.dart_target(&g.twin(d)))
 }
 
@@ -83,6 +67,7 @@ mod tests {
     use crate::data_structure::graph_dcel::GraphDCEL;
     use crate::data_structure::link_graph::LinkGraph;
     use crate::embedding::{index::Embedding, maximal_planar::index::MaximalPlanar};
+    use crate::generation::planar::generate;
     use crate::utils::convert::UndirectedGraph;
     use petgraph::stable_graph::StableGraph;
     use std::collections::HashSet;
@@ -131,4 +116,23 @@ mod tests {
         assert_eq!(dual.len(), 1);
         assert!(dual.get(&lof).unwrap_or(&HashSet::new()).contains(&lf));
     }
+
+    #[test]
+    fn spanning_face_tree() {
+        for n in 4..30 {
+            let lg = MaximalPlanar::embed(generate(n, Some(n as u64)).to_pet_graph());
+            let span = Span::compute(&lg, lg.get_vertexes().next().unwrap());
+            let dual = dual_graph(&lg, &span);
+            let faces: HashSet<_> = lg.get_darts().map(|d| lg.face(&d)).collect();
+            let mut reached: HashSet<_> = dual.keys().cloned().collect();
+            reached.extend(dual.values().flatten().cloned());
+
+            // the edges not in the span tree form a spanning tree of the faces
+            assert_eq!(reached, faces);
+            assert_eq!(
+                dual.values().map(|c| c.len()).sum::<usize>(),
+                faces.len() - 1
+            );
+        }
+    }
 }
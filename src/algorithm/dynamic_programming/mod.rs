@@ -7,4 +7,4 @@ mod min_vertex_cover;
 #[allow(dead_code)]
 pub mod solve;
 #[allow(dead_code)]
-mod utils;
+pub(crate) mod utils;
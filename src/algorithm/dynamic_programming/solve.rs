@@ -12,12 +12,21 @@
 use super::{max_independent_set, min_vertex_cover};
 use crate::{
     algorithm::{
-        dynamic_programming::utils::remap_vertices,
+        dynamic_programming::utils::{
+            remap_tree_decomposition, remap_vertices, restrict_tree_decomposition,
+        },
+        elimination_ordering::{min_degree_decomposition, min_fill_decomposition},
         nice_tree_decomposition::{get_children, NiceTdNodeType, NiceTreeDecomposition},
+        tree_decomposition::face_tree_decomposition,
     },
+    data_structure::link_graph::LinkGraph,
     utils::convert::{to_hash_map_graph, UndirectedGraph},
 };
-use arboretum_td::{graph::HashMapGraph, solver::Solver, tree_decomposition::TreeDecomposition};
+use arboretum_td::{
+    graph::{BaseGraph, HashMapGraph},
+    solver::Solver,
+    tree_decomposition::TreeDecomposition,
+};
 use bitvec::vec::BitVec;
 use fxhash::FxHashSet;
 use std::collections::{HashMap, HashSet};
@@ -165,10 +174,51 @@ impl DpProblem {
     }
 }
 
+/// Selects how the tree decomposition used by the dynamic programming is calculated.
+pub enum TdStrategy<'a> {
+    /// Optimal tree decomposition calculated by the exact solver of arboretum.
+    Exact,
+    /// Tree decomposition given by the min-degree elimination heuristic.
+    MinDegree,
+    /// Tree decomposition given by the min-fill elimination heuristic.
+    MinFill,
+    /// Face tree decomposition of the given embedding.
+    /// The vertex ids of the embedding are used as the vertex ids of the input graph.
+    FaceTree(&'a LinkGraph),
+    /// Tree decomposition containing the vertex ids of the input graph.
+    Custom(TreeDecomposition),
+}
+
+impl TdStrategy<'_> {
+    /// Returns a tree decomposition of the input graph according to the strategy.
+    ///
+    /// The decompositions of the `FaceTree` and `Custom` strategies may belong to a supergraph
+    /// of the input graph. They are restricted to the vertices of the input graph.
+    pub fn tree_decomposition(&self, graph: &HashMapGraph) -> TreeDecomposition {
+        match self {
+            TdStrategy::Exact => {
+                let (remapped_graph, mapping) = remap_vertices(graph);
+                let td = Solver::auto(&remapped_graph).solve(&remapped_graph);
+                remap_tree_decomposition(&td, &mapping)
+            }
+            TdStrategy::MinDegree => min_degree_decomposition(graph),
+            TdStrategy::MinFill => min_fill_decomposition(graph),
+            TdStrategy::FaceTree(embedding) => {
+                restrict_tree_decomposition(&face_tree_decomposition(*embedding), &vertices(graph))
+            }
+            TdStrategy::Custom(td) => restrict_tree_decomposition(td, &vertices(graph)),
+        }
+    }
+}
+
+fn vertices(graph: &HashMapGraph) -> FxHashSet<usize> {
+    graph.vertices().collect()
+}
+
 /// Solves the given problem on the input graph using dynamic programming.
 ///
 /// When `td` is `None`, an optimal tree decomposition is calculated and used
-/// for the algorithm.
+/// for the algorithm. Otherwise the bags of `td` contain the vertex ids of the input graph.
 ///
 /// The `prob` parameter specifies whether the problem is a minimization
 /// or maximization problem and contains the "recipe" for how to calculate
@@ -186,9 +236,42 @@ pub fn dp_solve_hashmap_graph(
     graph: &HashMapGraph,
     td: Option<TreeDecomposition>,
     prob: &DpProblem,
+) -> HashSet<usize> {
+    let td = match td {
+        Some(td) => TdStrategy::Custom(td),
+        None => TdStrategy::Exact,
+    };
+    dp_solve_hashmap_graph_with_strategy(graph, &td, prob)
+}
+
+/// Solves the given problem like `dp_solve`, but the tree decomposition used for the
+/// algorithm is calculated according to `td`.
+pub fn dp_solve_with_strategy(
+    graph: &UndirectedGraph,
+    td: &TdStrategy,
+    prob: &DpProblem,
+) -> HashSet<usize> {
+    dp_solve_hashmap_graph_with_strategy(&to_hash_map_graph(graph), td, prob)
+}
+
+/// For convenience.
+pub fn dp_solve_hashmap_graph_with_strategy(
+    graph: &HashMapGraph,
+    td: &TdStrategy,
+    prob: &DpProblem,
+) -> HashSet<usize> {
+    dp_solve_tree_decomposition(graph, td.tree_decomposition(graph), prob)
+}
+
+/// Solves the given problem using a tree decomposition containing the vertex ids of the graph.
+pub(crate) fn dp_solve_tree_decomposition(
+    graph: &HashMapGraph,
+    td: TreeDecomposition,
+    prob: &DpProblem,
 ) -> HashSet<usize> {
     let (graph, mapping) = remap_vertices(graph);
-    let td = td.unwrap_or_else(|| Solver::auto(&graph).solve(&graph));
+    let forward_mapping = mapping.iter().map(|(i, v)| (*v, *i)).collect();
+    let td = remap_tree_decomposition(&td, &forward_mapping);
     let nice_td = NiceTreeDecomposition::new(td);
 
     assert!(nice_td.td.verify(&graph).is_ok());
@@ -293,11 +376,14 @@ fn dp_read_solution_from_table_rec(
 
 #[cfg(test)]
 mod tests {
-    use super::dp_solve_hashmap_graph;
+    use super::{dp_solve_hashmap_graph, dp_solve_hashmap_graph_with_strategy, TdStrategy};
     use crate::{
-        algorithm::dynamic_programming::{
-            solve::{remap_vertices, DpProblem},
-            utils::init_bit_vec,
+        algorithm::{
+            dynamic_programming::{
+                solve::{remap_vertices, DpProblem},
+                utils::init_bit_vec,
+            },
+            elimination_ordering::min_degree_decomposition,
         },
         generation::erdos_renyi::generate_hash_map_graph,
         utils::{
@@ -381,6 +467,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn max_independent_set_strategies() {
+        for n in 2..15 {
+            let graph = generate_hash_map_graph(n, 0.3, Some(n as u64));
+            let mut subgraph = graph.clone();
+            subgraph.remove_vertex(0);
+            let prob = DpProblem::max_independent_set();
+            let expected = brute_force_max_independent_set(&graph).len();
+
+            for strategy in [
+                TdStrategy::MinDegree,
+                TdStrategy::MinFill,
+                TdStrategy::Custom(min_degree_decomposition(&graph)),
+            ] {
+                let sol = dp_solve_hashmap_graph_with_strategy(&graph, &strategy, &prob);
+                assert!(is_independent_set(&graph, &sol));
+                assert_eq!(sol.len(), expected);
+
+                let sol = dp_solve_hashmap_graph_with_strategy(&subgraph, &strategy, &prob);
+                assert!(is_independent_set(&subgraph, &sol));
+            }
+        }
+    }
+
     #[test]
     fn min_vertex_cover_isolated() {
         for n in 1..10 {
@@ -1,4 +1,7 @@
-use arboretum_td::graph::{BaseGraph, HashMapGraph, MutableGraph};
+use arboretum_td::{
+    graph::{BaseGraph, HashMapGraph, MutableGraph},
+    tree_decomposition::TreeDecomposition,
+};
 use bitvec::vec::BitVec;
 use fxhash::FxHashSet;
 use itertools::Itertools;
@@ -55,3 +58,73 @@ pub fn remap_vertices(graph: &HashMapGraph) -> (HashMapGraph, HashMap<usize, usi
 
     (remapped_graph, backward_mapping)
 }
+
+// the result is the given tree decomposition with its vertices renamed by `mapping`.
+pub fn remap_tree_decomposition(
+    td: &TreeDecomposition,
+    mapping: &HashMap<usize, usize>,
+) -> TreeDecomposition {
+    let mut remapped_td = TreeDecomposition::default();
+
+    for bag in td.bags() {
+        remapped_td.add_bag(bag.vertex_set.iter().map(|v| mapping[v]).collect());
+    }
+
+    for bag in td.bags() {
+        for neighbor in bag.neighbors.iter().filter(|neighbor| **neighbor > bag.id) {
+            remapped_td.add_edge(bag.id, *neighbor);
+        }
+    }
+
+    remapped_td.root = td.root;
+    remapped_td
+}
+
+// the result is the given tree decomposition with every bag intersected with `vertices`.
+// bags becoming empty are removed and their neighbors are connected instead.
+pub fn restrict_tree_decomposition(
+    td: &TreeDecomposition,
+    vertices: &FxHashSet<usize>,
+) -> TreeDecomposition {
+    let mut adjacency: Vec<FxHashSet<usize>> =
+        td.bags().iter().map(|bag| bag.neighbors.clone()).collect();
+    let bags: Vec<FxHashSet<usize>> = td
+        .bags()
+        .iter()
+        .map(|bag| bag.vertex_set.intersection(vertices).copied().collect())
+        .collect();
+
+    for id in (0..bags.len()).filter(|id| bags[*id].is_empty()) {
+        let neighbors: Vec<usize> = adjacency[id].drain().collect();
+
+        for u in &neighbors {
+            adjacency[*u].remove(&id);
+        }
+
+        if let Some((first, rest)) = neighbors.split_first() {
+            for u in rest {
+                adjacency[*first].insert(*u);
+                adjacency[*u].insert(*first);
+            }
+        }
+    }
+
+    let mut restricted_td = TreeDecomposition::default();
+    let mut mapping = HashMap::new();
+
+    for (id, bag) in bags
+        .into_iter()
+        .enumerate()
+        .filter(|(_, bag)| !bag.is_empty())
+    {
+        mapping.insert(id, restricted_td.add_bag(bag));
+    }
+
+    for (id, neighbors) in adjacency.iter().enumerate() {
+        for neighbor in neighbors.iter().filter(|neighbor| **neighbor > id) {
+            restricted_td.add_edge(mapping[&id], mapping[neighbor]);
+        }
+    }
+
+    restricted_td
+}
@@ -0,0 +1,258 @@
+//! Contains heuristics computing tree decompositions from elimination orderings.
+//!
+//! Eliminating a vertex turns its neighborhood into a clique and removes the vertex.
+//! Every eliminated vertex together with its neighborhood forms a bag, which is attached
+//! to the bag of the neighbor eliminated next.
+//!
+//! ```rust
+//! use graph_algo_ptas::algorithm::elimination_ordering::min_degree_decomposition;
+//! use arboretum_td::graph::{HashMapGraph, MutableGraph};
+//!
+//! let mut graph = HashMapGraph::new();
+//! graph.add_edge(0, 1);
+//! graph.add_edge(1, 2);
+//! let td = min_degree_decomposition(&graph);
+//! assert!(td.verify(&graph).is_ok());
+//! ```
+
+use arboretum_td::{
+    graph::{BaseGraph, HashMapGraph},
+    tree_decomposition::TreeDecomposition,
+};
+use fxhash::FxHashSet;
+use std::collections::{BTreeSet, HashMap};
+
+type Adjacency = HashMap<usize, FxHashSet<usize>>;
+
+/// Returns a tree decomposition given by repeatedly eliminating a vertex of minimum degree.
+pub fn min_degree_decomposition(graph: &HashMapGraph) -> TreeDecomposition {
+    elimination_decomposition::<DegreeQueue>(graph)
+}
+
+/// Returns a tree decomposition given by repeatedly eliminating a vertex
+/// whose elimination adds the fewest edges.
+pub fn min_fill_decomposition(graph: &HashMapGraph) -> TreeDecomposition {
+    elimination_decomposition::<FillQueue>(graph)
+}
+
+// Selects the next vertex to eliminate
+trait EliminationQueue {
+    fn new(adjacency: &Adjacency) -> Self;
+
+    fn pop(&mut self) -> Option<usize>;
+
+    // Updates the queue after the vertex with the given former neighbors was eliminated
+    fn update(&mut self, adjacency: &Adjacency, neighbors: &FxHashSet<usize>);
+}
+
+// Bucket queue of the vertices by their degree, ties are broken by the smallest vertex
+struct DegreeQueue {
+    buckets: Vec<BTreeSet<usize>>,
+    degree: HashMap<usize, usize>,
+    min: usize,
+}
+
+impl EliminationQueue for DegreeQueue {
+    fn new(adjacency: &Adjacency) -> Self {
+        let mut queue = DegreeQueue {
+            buckets: vec![BTreeSet::new(); adjacency.len()],
+            degree: HashMap::new(),
+            min: 0,
+        };
+
+        for (&v, neighbors) in adjacency {
+            queue.buckets[neighbors.len()].insert(v);
+            queue.degree.insert(v, neighbors.len());
+        }
+
+        queue
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        while self.min < self.buckets.len() {
+            if let Some(v) = self.buckets[self.min].pop_first() {
+                self.degree.remove(&v);
+                return Some(v);
+            }
+            self.min += 1;
+        }
+
+        None
+    }
+
+    // only the degrees of the former neighbors change
+    fn update(&mut self, adjacency: &Adjacency, neighbors: &FxHashSet<usize>) {
+        for u in neighbors {
+            let degree = adjacency[u].len();
+            let old = self.degree.insert(*u, degree).unwrap();
+            self.buckets[old].remove(u);
+            self.buckets[degree].insert(*u);
+            self.min = self.min.min(degree);
+        }
+    }
+}
+
+// Priority queue of the vertices by their fill-in, ties are broken by the smallest vertex
+struct FillQueue {
+    queue: BTreeSet<(usize, usize)>,
+    fill: HashMap<usize, usize>,
+}
+
+impl EliminationQueue for FillQueue {
+    fn new(adjacency: &Adjacency) -> Self {
+        let fill = adjacency
+            .iter()
+            .map(|(&v, neighbors)| (v, fill_in(adjacency, neighbors)))
+            .collect::<HashMap<_, _>>();
+
+        FillQueue {
+            queue: fill.iter().map(|(&v, &fill)| (fill, v)).collect(),
+            fill,
+        }
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        let (_, v) = self.queue.pop_first()?;
+        self.fill.remove(&v);
+        Some(v)
+    }
+
+    // the fill-in changes only for the former neighbors, whose neighborhood changed, and their
+    // neighbors, which may have gained edges between their neighbors
+    fn update(&mut self, adjacency: &Adjacency, neighbors: &FxHashSet<usize>) {
+        let affected = neighbors
+            .iter()
+            .flat_map(|u| adjacency[u].iter().chain([u]))
+            .copied()
+            .collect::<FxHashSet<_>>();
+
+        for u in affected {
+            let fill = fill_in(adjacency, &adjacency[&u]);
+            let old = self.fill.insert(u, fill).unwrap();
+            self.queue.remove(&(old, u));
+            self.queue.insert((fill, u));
+        }
+    }
+}
+
+fn fill_in(adjacency: &Adjacency, neighbors: &FxHashSet<usize>) -> usize {
+    neighbors
+        .iter()
+        .map(|u| {
+            neighbors
+                .iter()
+                .filter(|w| u < w && !adjacency[u].contains(w))
+                .count()
+        })
+        .sum()
+}
+
+fn elimination_decomposition<Q: EliminationQueue>(graph: &HashMapGraph) -> TreeDecomposition {
+    let mut adjacency: Adjacency = graph
+        .vertices()
+        .map(|v| (v, graph.neighborhood(v).filter(|u| *u != v).collect()))
+        .collect();
+    let mut queue = Q::new(&adjacency);
+    let mut td = TreeDecomposition::default();
+    let mut bag_ids = HashMap::new();
+    let mut eliminated = vec![];
+
+    while let Some(v) = queue.pop() {
+        let neighbors = adjacency.remove(&v).unwrap();
+
+        for u in &neighbors {
+            let u_neighbors = adjacency.get_mut(u).unwrap();
+            u_neighbors.remove(&v);
+            u_neighbors.extend(neighbors.iter().filter(|w| *w != u));
+        }
+        queue.update(&adjacency, &neighbors);
+
+        let mut bag = neighbors.clone();
+        bag.insert(v);
+        bag_ids.insert(v, td.add_bag(bag));
+        eliminated.push((v, neighbors));
+    }
+
+    let last = td.bags().len().saturating_sub(1);
+
+    for (v, neighbors) in eliminated {
+        let id = bag_ids[&v];
+        let parent = neighbors.iter().map(|u| bag_ids[u]).min().unwrap_or(last);
+
+        if parent != id {
+            td.add_edge(id, parent);
+        }
+    }
+
+    td
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{min_degree_decomposition, min_fill_decomposition};
+    use crate::{generation::planar::generate, utils::convert::to_hash_map_graph};
+    use arboretum_td::graph::{HashMapGraph, MutableGraph};
+
+    fn width(td: &arboretum_td::tree_decomposition::TreeDecomposition) -> usize {
+        td.bags().iter().map(|b| b.vertex_set.len()).max().unwrap() - 1
+    }
+
+    #[test]
+    fn tree() {
+        let mut graph = HashMapGraph::new();
+
+        for v in 1..20 {
+            graph.add_edge(v, v / 2);
+        }
+
+        for td in [
+            min_degree_decomposition(&graph),
+            min_fill_decomposition(&graph),
+        ] {
+            assert!(td.verify(&graph).is_ok());
+            assert_eq!(width(&td), 1);
+        }
+    }
+
+    #[test]
+    fn disconnected() {
+        let mut graph = HashMapGraph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(2, 3);
+        graph.add_vertex(4);
+
+        for td in [
+            min_degree_decomposition(&graph),
+            min_fill_decomposition(&graph),
+        ] {
+            assert!(td.verify(&graph).is_ok());
+        }
+    }
+
+    #[test]
+    fn cycle() {
+        let mut graph = HashMapGraph::new();
+
+        for v in 0..20 {
+            graph.add_edge(v, (v + 1) % 20);
+        }
+
+        for td in [
+            min_degree_decomposition(&graph),
+            min_fill_decomposition(&graph),
+        ] {
+            assert!(td.verify(&graph).is_ok());
+            assert_eq!(width(&td), 2);
+        }
+    }
+
+    #[test]
+    fn random_planar() {
+        for n in (4..40).chain([300]) {
+            let graph = to_hash_map_graph(&generate(n, Some(n as u64)).to_pet_graph());
+
+            assert!(min_degree_decomposition(&graph).verify(&graph).is_ok());
+            assert!(min_fill_decomposition(&graph).verify(&graph).is_ok());
+        }
+    }
+}
@@ -1,7 +1,8 @@
 //! Contains the implementation of Leveling
 use crate::algorithm::spantree::Span;
-use crate::data_structure::link_graph::LinkVertex;
+use crate::data_structure::graph_dcel::{Dart, Face, GraphDCEL, Vertex};
 use std::collections::HashSet;
+use std::hash::Hash;
 
 /// The structure containing the levels of a graph
 pub struct Leveling<T> {
@@ -9,9 +10,9 @@ pub struct Leveling<T> {
     pub levels: Vec<HashSet<T>>,
 }
 
-impl Leveling<LinkVertex> {
+impl<T: Eq + Hash + Clone> Leveling<T> {
     /// Returns a new Leveling of a graph along its span tree
-    pub fn compute(span: Span<LinkVertex>) -> Self {
+    pub fn compute(span: Span<T>) -> Self {
         let mut result = vec![];
         let mut level = HashSet::new();
         level.insert(span.root);
@@ -31,6 +32,43 @@ impl Leveling<LinkVertex> {
         Leveling { levels: result }
     }
 
+    /// Returns a new Leveling of a connected graph by breadth first search from the vertices
+    /// of the given face, which form the first level.
+    ///
+    /// Rooted at the outer face, every level is part of the outer face once the lower levels
+    /// are removed, and there are at most as many levels as from any single vertex of the face.
+    pub fn from_face<
+        D: Dart + Eq + Clone,
+        F: Face,
+        VI: Iterator<Item = T>,
+        DI: Iterator<Item = D>,
+        FI: Iterator<Item = F>,
+    >(
+        g: &impl GraphDCEL<T, D, F, VI, DI, FI>,
+        face: &F,
+    ) -> Self
+    where
+        T: Vertex,
+    {
+        let mut visited = HashSet::new();
+        let mut level = g
+            .face_vertices(face)
+            .filter(|v| visited.insert(v.clone()))
+            .collect::<HashSet<_>>();
+        let mut result = vec![];
+
+        while !level.is_empty() {
+            let new_level = level
+                .iter()
+                .flat_map(|v| g.neighbors(v))
+                .filter(|v| visited.insert(v.clone()))
+                .collect();
+            result.push(level);
+            level = new_level;
+        }
+        Leveling { levels: result }
+    }
+
     /// Returns the number of levels
     pub fn size(&self) -> usize {
         self.levels.len()
@@ -38,14 +76,10 @@ impl Leveling<LinkVertex> {
 
     /// Returns rings consisting of k levels.
     /// The last ring can contain less than k levels if the number of levels mod k is not 0.
-    pub fn rings(&self, k: usize) -> Vec<HashSet<LinkVertex>> {
+    pub fn rings(&self, k: usize) -> Vec<HashSet<T>> {
         let mut result = vec![];
-        for c in self
-            .levels
-            .chunks(k)
-            .collect::<Vec<&[HashSet<LinkVertex>]>>()
-        {
-            let mut union: HashSet<LinkVertex> = HashSet::new();
+        for c in self.levels.chunks(k).collect::<Vec<&[HashSet<T>]>>() {
+            let mut union: HashSet<T> = HashSet::new();
             for set in c {
                 union.extend(set.clone());
             }
@@ -58,8 +92,12 @@ impl Leveling<LinkVertex> {
 #[cfg(test)]
 mod tests {
     use crate::algorithm::leveling::Leveling;
+    use crate::algorithm::outerplanarity::min_outerplanarity_face;
     use crate::algorithm::spantree::Span;
+    use crate::data_structure::graph_dcel::GraphDCEL;
     use crate::data_structure::link_graph::{LinkGraph, LinkVertex};
+    use crate::embedding::{index::Embedding, maximal_planar::index::MaximalPlanar};
+    use crate::generation::planar::generate;
     use std::collections::HashSet;
 
     #[test]
@@ -468,6 +506,33 @@ mod tests {
         test_rings(leveling, cs);
     }
 
+    #[test]
+    fn from_outer_face() {
+        let mut graph = MaximalPlanar::embed(generate(50, Some(2)).to_pet_graph());
+        let face = min_outerplanarity_face(&graph).unwrap();
+        graph.set_outer_face(&face);
+
+        let leveling = Leveling::from_face(&graph, &graph.outer_face().unwrap());
+        assert_eq!(
+            leveling.levels[0],
+            graph.face_vertices(&face).collect::<HashSet<_>>()
+        );
+        assert_eq!(
+            leveling
+                .levels
+                .iter()
+                .map(|level| level.len())
+                .sum::<usize>(),
+            graph.vertex_count()
+        );
+
+        // rooting at a single vertex of the outer face never needs fewer levels
+        for vertex in graph.face_vertices(&face) {
+            let span = Span::compute(&graph, vertex);
+            assert!(Leveling::compute(span).size() >= leveling.size());
+        }
+    }
+
     #[test]
     fn rings() {
         let mut lg = LinkGraph::new();
@@ -1,18 +1,30 @@
 //! Contains algorithms required for the PTAS.
 
+#[allow(dead_code)]
+pub mod distance;
 #[allow(dead_code)]
 pub mod dualgraph;
 #[allow(dead_code)]
 pub mod dynamic_programming;
 #[allow(dead_code)]
+pub mod elimination_ordering;
+#[allow(dead_code)]
 pub mod leveling;
 #[allow(dead_code)]
 pub mod nice_tree_decomposition;
 #[allow(dead_code)]
+pub mod outerplanarity;
+#[allow(dead_code)]
 pub mod ptas;
 #[allow(dead_code)]
+pub mod separator;
+#[allow(dead_code)]
+pub mod separator_ptas;
+#[allow(dead_code)]
 pub mod spantree;
 #[allow(dead_code)]
+pub mod subgraph_isomorphism;
+#[allow(dead_code)]
 pub mod tree_decomposition;
 #[allow(dead_code)]
 pub mod triangulation;
@@ -28,7 +28,12 @@ pub struct NiceTreeDecomposition {
 
 impl NiceTreeDecomposition {
     /// Create a nice tree decomposition.
-    pub fn new(mut td: TreeDecomposition) -> Self {
+    ///
+    /// Bags which are a subset of a neighboring bag are contracted into that neighbor first,
+    /// since the nicification does not support neighboring bags with equal vertex sets. So the
+    /// bag ids of the result only match the input if no bag was contracted.
+    pub fn new(td: TreeDecomposition) -> Self {
+        let mut td = Self::remove_redundant_bags(td);
         let root = td.root.unwrap_or(0);
         td.root = Some(root);
 
@@ -53,6 +58,66 @@ impl NiceTreeDecomposition {
         }
     }
 
+    // contracts every bag which is a subset of a neighboring bag into that neighbor, this
+    // does not change the width. The ids of the remaining bags keep their order.
+    fn remove_redundant_bags(td: TreeDecomposition) -> TreeDecomposition {
+        let mut representative: Vec<usize> = (0..td.bags().len()).collect();
+        let find = |representative: &Vec<usize>, mut id: usize| {
+            while representative[id] != id {
+                id = representative[id];
+            }
+            id
+        };
+        let mut changed = true;
+
+        while changed {
+            changed = false;
+
+            for bag in td.bags() {
+                for neighbor in &bag.neighbors {
+                    let a = find(&representative, bag.id);
+                    let b = find(&representative, *neighbor);
+
+                    if a != b && td.bags()[a].vertex_set.is_subset(&td.bags()[b].vertex_set) {
+                        representative[a] = b;
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        if representative.iter().enumerate().all(|(id, r)| id == *r) {
+            return td;
+        }
+
+        let mut reduced_td = TreeDecomposition::default();
+        let mut mapping = vec![None; td.bags().len()];
+
+        for bag in td
+            .bags()
+            .iter()
+            .filter(|bag| representative[bag.id] == bag.id)
+        {
+            mapping[bag.id] = Some(reduced_td.add_bag(bag.vertex_set.clone()));
+        }
+
+        for bag in td.bags() {
+            for neighbor in &bag.neighbors {
+                let a = mapping[find(&representative, bag.id)].unwrap();
+                let b = mapping[find(&representative, *neighbor)].unwrap();
+
+                if a < b {
+                    reduced_td.add_edge(a, b);
+                }
+            }
+        }
+
+        reduced_td.root = td
+            .root
+            .map(|root| mapping[find(&representative, root)].unwrap());
+        reduced_td
+    }
+
     fn nicify_multi_child_nodes(
         id: usize,
         children: &FxHashSet<usize>,
@@ -371,6 +436,33 @@ mod tests {
     use fxhash::FxHashSet;
     use rand::{rngs::StdRng, Rng, SeedableRng};
 
+    #[test]
+    fn equal_neighboring_bags() {
+        // the path 0 - 1 - 2 - 3 with bags {0, 1}, {0, 1}, {0, 1}, {1, 2} used to fail the
+        // check for a nice tree decomposition, since the equal bags were not merged
+        let mut td = TreeDecomposition::default();
+        let bags = [vec![0, 1], vec![0, 1], vec![0, 1], vec![1, 2]];
+
+        for (i, bag) in bags.iter().enumerate() {
+            td.add_bag(bag.iter().copied().collect());
+
+            if i > 0 {
+                td.add_edge(i - 1, i);
+            }
+        }
+
+        let nice_td = NiceTreeDecomposition::new(td);
+        let max_bag_size = nice_td
+            .td
+            .bags()
+            .iter()
+            .map(|bag| bag.vertex_set.len())
+            .max();
+
+        assert_eq!(max_bag_size, Some(2));
+        assert_eq!(nice_td.mapping.len(), nice_td.td.bags().len());
+    }
+
     #[test]
     fn single_bag_with_1_vertex() {
         let mut td = TreeDecomposition::default();
@@ -0,0 +1,201 @@
+//! Contains functions for the outerplanarity of embedded graphs.
+//!
+//! A graph is k-outerplanar if removing the vertexes of the outer face k times leaves
+//! an empty graph. The vertexes removed in one step form a level of the graph.
+//!
+//! ```rust
+//! use graph_algo_ptas::algorithm::outerplanarity::{min_outerplanarity_face, outerplanarity};
+//! use graph_algo_ptas::embedding::{index::Embedding, maximal_planar::index::MaximalPlanar};
+//! use graph_algo_ptas::generation::planar::generate;
+//!
+//! let mut graph = MaximalPlanar::embed(generate(50, Some(1)).to_pet_graph());
+//! let k = outerplanarity(&graph);
+//! let face = min_outerplanarity_face(&graph).unwrap();
+//! graph.set_outer_face(&face);
+//! assert!(outerplanarity(&graph) <= k);
+//! ```
+
+use crate::algorithm::leveling::Leveling;
+use crate::data_structure::graph_dcel::{Dart, Face, GraphDCEL, Vertex};
+use crate::data_structure::link_graph::LinkGraph;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Returns the minimum k such that the connected graph is k-outerplanar with its
+/// current outer face
+pub fn outerplanarity<N, E, F>(graph: &LinkGraph<N, E, F>) -> usize {
+    match graph.outer_face() {
+        Some(face) => outerplanar_leveling(graph, &face).size(),
+        None => usize::from(graph.vertex_count() > 0),
+    }
+}
+
+/// Returns the levels obtained by repeatedly removing the vertexes of the outer face of
+/// a connected graph, starting with the given outer face
+pub fn outerplanar_leveling<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    graph: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+    outer_face: &F,
+) -> Leveling<V> {
+    Leveling {
+        levels: peel(graph, outer_face, usize::MAX),
+    }
+}
+
+/// Returns the face of a connected graph which minimizes the outerplanarity of the graph
+/// when used as outer face.
+///
+/// Every face is peeled once, so the running time is O(n²). Peeling a face stops as soon
+/// as it needs more levels than the best face found so far.
+pub fn min_outerplanarity_face<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    graph: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+) -> Option<F> {
+    let mut best = None;
+    let mut best_k = usize::MAX;
+
+    for face in graph.get_faces() {
+        let k = peel(graph, &face, best_k).len();
+
+        if k < best_k {
+            best = Some(face);
+            best_k = k;
+        }
+    }
+
+    best
+}
+
+// Returns the levels peeled off the graph when starting at the given outer face. Removing
+// the vertexes of a level merges all faces incident to them into the outer face, so the next
+// level consists of the remaining vertexes sharing a face with the level. Stops as soon as
+// `bound` levels are reached.
+fn peel<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    graph: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+    outer_face: &F,
+    bound: usize,
+) -> Vec<HashSet<V>> {
+    let mut visited_faces = HashSet::from([outer_face.clone()]);
+    let mut visited_vertexes = HashSet::new();
+    let mut faces = vec![outer_face.clone()];
+    let mut levels = vec![];
+
+    while levels.len() < bound {
+        let level = faces
+            .iter()
+            .flat_map(|face| graph.face_vertices(face))
+            .filter(|vertex| visited_vertexes.insert(vertex.clone()))
+            .collect::<HashSet<_>>();
+
+        if level.is_empty() {
+            break;
+        }
+
+        faces = level
+            .iter()
+            .flat_map(|vertex| graph.incident_faces(vertex))
+            .filter(|face| visited_faces.insert(face.clone()))
+            .collect();
+        levels.push(level);
+    }
+
+    levels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{min_outerplanarity_face, outerplanar_leveling, outerplanarity, peel};
+    use crate::data_structure::{
+        graph_dcel::GraphDCEL,
+        link_graph::{example::three_ring_graph, LinkGraph},
+    };
+    use crate::embedding::{index::Embedding, maximal_planar::index::MaximalPlanar};
+    use crate::generation::planar::generate;
+
+    #[test]
+    fn trivial() {
+        let mut graph = LinkGraph::new();
+        assert_eq!(outerplanarity(&graph), 0);
+
+        let v1 = graph.new_vertex();
+        assert_eq!(outerplanarity(&graph), 1);
+
+        let v2 = graph.new_vertex();
+        let dart = graph.new_dart(v1.clone(), v2.clone(), None, None, None, None);
+        let face = graph.new_face(dart.clone());
+        graph.new_dart(
+            v2,
+            v1,
+            Some(dart.clone()),
+            Some(dart.clone()),
+            Some(dart),
+            Some(face),
+        );
+        assert_eq!(outerplanarity(&graph), 1);
+    }
+
+    #[test]
+    fn k4() {
+        let graph = MaximalPlanar::embed(generate(3, None).to_pet_graph());
+        let face = min_outerplanarity_face(&graph).unwrap();
+
+        // every face of K4 leaves one inner vertex
+        assert_eq!(graph.vertex_count(), 4);
+        assert_eq!(outerplanar_leveling(&graph, &face).size(), 2);
+        assert_eq!(outerplanarity(&graph), 2);
+    }
+
+    #[test]
+    fn three_rings() {
+        let (mut graph, _, darts) = three_ring_graph();
+        // all faces except the one bounded by the outer ring are triangles
+        let outer_face = darts
+            .iter()
+            .map(|dart| graph.face(dart))
+            .find(|face| graph.face_vertex_count(face) == 8)
+            .unwrap();
+        let leveling = outerplanar_leveling(&graph, &outer_face);
+
+        assert_eq!(
+            leveling.levels.iter().map(|l| l.len()).collect::<Vec<_>>(),
+            vec![8, 4, 1]
+        );
+        assert_eq!(peel(&graph, &outer_face, 2).len(), 2);
+
+        graph.set_outer_face(&outer_face);
+        assert_eq!(outerplanarity(&graph), 3);
+    }
+
+    #[test]
+    fn minimal() {
+        for seed in 0..5 {
+            let graph: LinkGraph = MaximalPlanar::embed(generate(40, Some(seed)).to_pet_graph());
+            let best = min_outerplanarity_face(&graph).unwrap();
+            let k = outerplanar_leveling(&graph, &best).size();
+
+            assert!(graph
+                .get_darts()
+                .all(|dart| outerplanar_leveling(&graph, &graph.face(&dart)).size() >= k));
+            assert!(outerplanarity(&graph) >= k);
+        }
+    }
+}
@@ -9,11 +9,43 @@
 //! let sol = ptas(&graph, &DpProblem::max_independent_set(), 0.5);
 //! ```
 
-use super::dynamic_programming::solve::{dp_solve_hashmap_graph, DpObjective, DpProblem};
+use super::dynamic_programming::solve::{
+    dp_solve_tree_decomposition, DpObjective, DpProblem, TdStrategy,
+};
+use crate::data_structure::{graph_dcel::GraphDCEL, link_graph::LinkGraph};
 use crate::utils::convert::UndirectedGraph;
-use arboretum_td::graph::{HashMapGraph, MutableGraph};
+use arboretum_td::graph::{BaseGraph, HashMapGraph, MutableGraph};
 use petgraph::{algo::kosaraju_scc, stable_graph::NodeIndex, visit::EdgeRef};
-use std::collections::{HashSet, VecDeque};
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
+
+/// Statistics about solving a single component of a ring decomposition.
+#[derive(Debug, Clone)]
+pub struct RingStats {
+    /// Index of the ring decomposition containing the component
+    pub decomposition: usize,
+    /// Number of vertices of the component
+    pub vertices: usize,
+    /// Width of the tree decomposition used for the component
+    pub width: usize,
+    /// Time spent calculating the tree decomposition
+    pub td_time: Duration,
+    /// Time spent on the dynamic programming
+    pub dp_time: Duration,
+}
+
+/// The solution calculated by the PTAS together with statistics for every solved component.
+#[derive(Debug, Clone)]
+pub struct PtasReport {
+    /// Vertices of the solution
+    pub solution: HashSet<usize>,
+    /// Statistics for every solved component
+    pub rings: Vec<RingStats>,
+    /// Number of breadth first search levels the rings are made of
+    pub levels: usize,
+}
 
 /// Calculates an approximate solution for the given problem on the input graph.
 /// The input graph is expected to be planar.
@@ -21,14 +53,90 @@ use std::collections::{HashSet, VecDeque};
 /// The solution is guaranteed to be (1 - eps) optimal for maximization problems
 /// and (1 + eps) optimal for minimization problems.
 pub fn ptas(graph: &UndirectedGraph, prob: &DpProblem, eps: f64) -> HashSet<usize> {
+    ptas_with_strategy(graph, prob, eps, &TdStrategy::Exact).solution
+}
+
+/// Calculates an approximate solution like `ptas`, but the tree decompositions of the rings
+/// are calculated according to `td`.
+///
+/// The returned report contains the time spent on every component of the rings.
+pub fn ptas_with_strategy(
+    graph: &UndirectedGraph,
+    prob: &DpProblem,
+    eps: f64,
+    td: &TdStrategy,
+) -> PtasReport {
+    solve_ring_decompositions(
+        get_ring_decompositions(&mut graph.clone(), &[], eps),
+        prob,
+        td,
+    )
+}
+
+/// Calculates an approximate solution like `ptas_with_strategy` on an embedded graph.
+///
+/// The levels of the rings are found by breadth first search from the vertexes of the outer
+/// face, so choosing an outer face with small outerplanarity, e.g. by
+/// `min_outerplanarity_face`, leads to fewer levels. The solution contains the ids of the
+/// vertexes of the embedding.
+pub fn ptas_embedded<N, E, F>(
+    graph: &LinkGraph<N, E, F>,
+    prob: &DpProblem,
+    eps: f64,
+    td: &TdStrategy,
+) -> PtasReport {
+    let roots = graph
+        .outer_face()
+        .map(|face| {
+            graph
+                .face_vertices(&face)
+                .map(|v| NodeIndex::new(v.get_id()))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    solve_ring_decompositions(
+        get_ring_decompositions(&mut graph.to_pet_graph(), &roots, eps),
+        prob,
+        td,
+    )
+}
+
+fn solve_ring_decompositions(
+    ring_decompositions: Vec<RingDecomposition>,
+    prob: &DpProblem,
+    td: &TdStrategy,
+) -> PtasReport {
     let mut sols: Vec<HashSet<usize>> = vec![];
+    let mut rings = vec![];
+    let levels = ring_decompositions.first().map_or(0, |rd| rd.levels);
 
-    for ring_decomposition in get_ring_decompositions(&mut graph.clone(), eps) {
+    for (i, ring_decomposition) in ring_decompositions.into_iter().enumerate() {
         let mut sol: HashSet<usize> = HashSet::new();
 
         for ring in get_component_graphs(&ring_decomposition.rings) {
-            let ring_sol = dp_solve_hashmap_graph(&ring, None, prob);
+            let start = Instant::now();
+            let ring_td = td.tree_decomposition(&ring);
+            let td_time = start.elapsed();
+            let width = ring_td
+                .bags()
+                .iter()
+                .map(|bag| bag.vertex_set.len())
+                .max()
+                .unwrap_or(0)
+                .saturating_sub(1);
+
+            let start = Instant::now();
+            let ring_sol = dp_solve_tree_decomposition(&ring, ring_td, prob);
             sol.extend(ring_sol.iter());
+
+            rings.push(RingStats {
+                decomposition: i,
+                vertices: ring.order(),
+                width,
+                td_time,
+                dp_time: start.elapsed(),
+            });
         }
 
         if prob.objective == DpObjective::Minimize {
@@ -47,7 +155,11 @@ pub fn ptas(graph: &UndirectedGraph, prob: &DpProblem, eps: f64) -> HashSet<usiz
         DpObjective::Maximize => sols.iter().max_by(|s1, s2| s1.len().cmp(&s2.len())),
     };
 
-    best_sol.unwrap().clone()
+    PtasReport {
+        solution: best_sol.unwrap().clone(),
+        rings,
+        levels,
+    }
 }
 
 fn get_component_graphs(graph: &UndirectedGraph) -> Vec<HashMapGraph> {
@@ -75,68 +187,79 @@ fn get_component_graphs(graph: &UndirectedGraph) -> Vec<HashMapGraph> {
 struct RingDecomposition {
     rings: UndirectedGraph,
     vertices_deleted: HashSet<NodeIndex>,
+    levels: usize,
 }
 
-fn get_ring_decompositions(graph: &mut UndirectedGraph, eps: f64) -> Vec<RingDecomposition> {
+// Deletes every k-th level of a breadth first search starting at all `roots` at once, or at
+// the first vertex if there are no roots
+fn get_ring_decompositions(
+    graph: &mut UndirectedGraph,
+    roots: &[NodeIndex],
+    eps: f64,
+) -> Vec<RingDecomposition> {
     let k = (1.0 / eps).ceil() as usize;
     assert!(kosaraju_scc(&graph.clone()).len() == 1);
     assert!(graph.node_count() > 0);
 
+    let levels = get_levels(graph, roots);
     let mut ring_decompositions: Vec<RingDecomposition> = vec![];
 
+    // the levels are numbered from 1, so level j + 1 is deleted if (j + 1) % k == i
     for i in 0..k {
         let mut rings = graph.clone();
         let mut vertices_deleted = HashSet::new();
-        let mut level = 1;
-        let start = graph.node_indices().next().unwrap();
-        let mut visited: HashSet<NodeIndex<u32>> = HashSet::new();
-        let mut queue: VecDeque<NodeIndex<u32>> = VecDeque::new();
-        let sep = NodeIndex::new(usize::max_value());
-        queue.push_back(start);
-        queue.push_back(sep);
-
-        while !queue.is_empty() {
-            let current = queue.pop_front().unwrap();
-
-            if current == sep {
-                level += 1;
-
-                if !queue.is_empty() {
-                    queue.push_back(sep);
-                }
 
-                continue;
-            }
-
-            if visited.contains(&current) {
-                continue;
-            }
-            visited.insert(current);
-
-            if level % k == i {
-                vertices_deleted.insert(current);
-                rings.remove_node(current);
-            }
-
-            for n in graph.neighbors(current) {
-                queue.push_back(n);
+        for level in levels.iter().skip((i + k - 1) % k).step_by(k) {
+            for v in level {
+                vertices_deleted.insert(*v);
+                rings.remove_node(*v);
             }
         }
 
         ring_decompositions.push(RingDecomposition {
             rings,
             vertices_deleted,
+            levels: levels.len(),
         });
     }
 
     ring_decompositions
 }
 
+/// Returns the levels of a breadth first search starting at all `roots` at once, or at the
+/// first vertex if there are no roots. Only the component of the roots is reached.
+pub(crate) fn get_levels(graph: &UndirectedGraph, roots: &[NodeIndex]) -> Vec<HashSet<NodeIndex>> {
+    let mut level: HashSet<NodeIndex> = match roots {
+        [] => graph.node_indices().take(1).collect(),
+        roots => roots.iter().copied().collect(),
+    };
+    let mut visited = level.clone();
+    let mut levels = vec![];
+
+    while !level.is_empty() {
+        let next_level = level
+            .iter()
+            .flat_map(|v| graph.neighbors(*v))
+            .filter(|u| visited.insert(*u))
+            .collect();
+        levels.push(level);
+        level = next_level;
+    }
+
+    levels
+}
+
 #[cfg(test)]
 mod tests {
-    use super::get_ring_decompositions;
+    use super::{get_ring_decompositions, ptas_embedded, ptas_with_strategy};
     use crate::{
-        algorithm::{dynamic_programming::solve::DpProblem, ptas::ptas},
+        algorithm::{
+            dynamic_programming::solve::{DpProblem, TdStrategy},
+            leveling::Leveling,
+            outerplanarity::min_outerplanarity_face,
+            ptas::ptas,
+        },
+        embedding::{index::Embedding, maximal_planar::index::MaximalPlanar},
         generation::{erdos_renyi::generate_petgraph, planar::generate},
         utils::{
             convert::{to_hash_map_graph, UndirectedGraph},
@@ -168,7 +291,7 @@ mod tests {
             i += 1;
 
             let eps = rng.gen_range(0.05..0.5) as f64;
-            let ring_decompositions = get_ring_decompositions(&mut graph.clone(), eps);
+            let ring_decompositions = get_ring_decompositions(&mut graph.clone(), &[], eps);
             let mut vertices = HashSet::new();
 
             for ring_decomposition in &ring_decompositions {
@@ -221,6 +344,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn max_independent_set_strategies() {
+        for n in 4..30 {
+            let lg = MaximalPlanar::embed(generate(n, Some(n as u64)).to_pet_graph());
+            let graph = lg.to_pet_graph();
+            let prob = DpProblem::max_independent_set();
+            let exact = ptas(&graph, &prob, 0.5);
+
+            for strategy in [
+                TdStrategy::MinDegree,
+                TdStrategy::MinFill,
+                TdStrategy::FaceTree(&lg),
+            ] {
+                let report = ptas_with_strategy(&graph, &prob, 0.5, &strategy);
+
+                assert!(is_independent_set(
+                    &to_hash_map_graph(&graph),
+                    &report.solution
+                ));
+                assert_eq!(report.solution.len(), exact.len());
+                assert!(!report.rings.is_empty());
+                assert!(report.rings.iter().all(|ring| ring.width < ring.vertices));
+            }
+        }
+    }
+
+    #[test]
+    fn max_independent_set_outer_face() {
+        for n in 4..30 {
+            let input = generate(n, Some(n as u64)).to_pet_graph();
+            let mut lg = MaximalPlanar::embed(input.clone());
+            let face = min_outerplanarity_face(&lg).unwrap();
+            lg.set_outer_face(&face);
+            let prob = DpProblem::max_independent_set();
+            let report = ptas_embedded(&lg, &prob, 0.5, &TdStrategy::Exact);
+
+            assert!(is_independent_set(
+                &to_hash_map_graph(&lg.to_pet_graph()),
+                &report.solution
+            ));
+            assert_eq!(report.levels, Leveling::from_face(&lg, &face).size());
+
+            if n <= 15 {
+                let sol = brute_force_max_independent_set(&to_hash_map_graph(&input));
+
+                assert!(report.solution.len() as f64 >= 0.5 * sol.len() as f64);
+            }
+        }
+    }
+
     #[test]
     fn min_vertex_cover_single_vertex() {
         let mut graph = UndirectedGraph::default();
@@ -0,0 +1,805 @@
+//! Contains the planar separator algorithm of Lipton and Tarjan.
+//!
+//! A separator is a set of vertices whose removal splits the graph into two parts
+//! `a` and `b` without edges between them, each containing at most `2n/3` vertices.
+//!
+//! The separator consists of two small bfs levels around the middle level and, if the levels
+//! between them are still too large, of a fundamental cycle in a triangulation of these levels
+//! with the levels above contracted into a single root. It contains at most `2 * sqrt(2n)`
+//! vertices and is computed in `O(n log n)` time.
+//!
+//! ```rust
+//! use graph_algo_ptas::generation::planar::generate;
+//! use graph_algo_ptas::embedding::{index::Embedding, maximal_planar::index::MaximalPlanar};
+//! use graph_algo_ptas::algorithm::separator::planar_separator;
+//!
+//! let graph = MaximalPlanar::embed(generate(100, Some(1)).to_pet_graph());
+//! let separator = planar_separator(&graph);
+//! assert!(3 * separator.a.len() <= 2 * 100 && 3 * separator.b.len() <= 2 * 100);
+//! ```
+
+use crate::data_structure::graph_dcel::{Dart, Face, GraphDCEL, Vertex};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// The structure containing a separator and the two parts separated by it
+#[derive(Debug)]
+pub struct Separator<T> {
+    /// Vertices of the separator
+    pub separator: HashSet<T>,
+    /// First part of the graph
+    pub a: HashSet<T>,
+    /// Second part of the graph
+    pub b: HashSet<T>,
+}
+
+/// Returns a separator of size `O(sqrt(n))` splitting the embedded graph into two parts
+/// of at most `2n/3` vertices each.
+pub fn planar_separator<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    g: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+) -> Separator<V> {
+    separate(&rotation_system(g), &g.get_vertexes().collect())
+}
+
+/// Returns a simple cycle which separates the graph into two parts of at most `2n/3`
+/// vertices each, together with the corresponding separator.
+///
+/// The cycle is a fundamental cycle of a bfs tree in a triangulation of the largest component,
+/// so its length is at most `2r + 1` for a component of radius `r`. All edges of the cycle but
+/// the one closing it belong to the graph, the closing edge belongs to the graph if it is
+/// triangulated. The cycle is empty if no component has more than `2n/3` vertices.
+pub fn cycle_separator<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    g: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+) -> (Vec<V>, Separator<V>) {
+    let rotation = rotation_system(g);
+    let vertices: HashSet<V> = g.get_vertexes().collect();
+    let cycle = balanced_tree_cycle(&rotation, &vertices).map_or(vec![], |(_, cycle)| cycle);
+    let separator = partition(&rotation, &vertices, cycle.iter().cloned().collect());
+
+    (cycle, separator)
+}
+
+/// Returns a separator of the subgraph induced by `vertices` consisting of the two paths of a
+/// fundamental cycle like [cycle_separator]. Both paths end at the common ancestor of the
+/// ends of the cycle in the bfs tree, so they are shortest paths in their component.
+pub(crate) fn shortest_path_separator<V: Hash + Eq + Clone>(
+    rotation: &HashMap<V, Vec<V>>,
+    vertices: &HashSet<V>,
+) -> (Vec<Vec<V>>, Separator<V>) {
+    let paths = balanced_tree_cycle(rotation, vertices).map_or(vec![], |(tree, cycle)| {
+        let top = (0..cycle.len())
+            .min_by_key(|&i| tree.level[&cycle[i]])
+            .unwrap();
+        vec![cycle[..=top].to_vec(), cycle[top..].to_vec()]
+    });
+    let separator = partition(
+        rotation,
+        vertices,
+        paths.iter().flatten().cloned().collect(),
+    );
+
+    (paths, separator)
+}
+
+// Returns a balanced fundamental cycle of a bfs tree of the component with more than 2n/3
+// vertices together with the tree, if there is such a component
+fn balanced_tree_cycle<V: Hash + Eq + Clone>(
+    rotation: &HashMap<V, Vec<V>>,
+    vertices: &HashSet<V>,
+) -> Option<(BfsTree<V>, Vec<V>)> {
+    let n = vertices.len();
+    let component = components(rotation, vertices, &HashSet::new())
+        .into_iter()
+        .find(|component| 3 * component.len() > 2 * n)?;
+    let root = component.iter().next().unwrap().clone();
+    let tree = BfsTree::compute(rotation, &component, root);
+    let cycle = Plane::new(rotation, &tree, &component, -1)
+        .balanced_cycle()
+        .into_iter()
+        .flatten()
+        .collect();
+
+    Some((tree, cycle))
+}
+
+/// Returns the neighbors of every vertex in the cyclic order of the embedding.
+pub(crate) fn rotation_system<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    g: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+) -> HashMap<V, Vec<V>> {
+    let connected: HashSet<V> = g.get_darts().map(|d| g.dart_target(&d)).collect();
+
+    g.get_vertexes()
+        .map(|v| {
+            let neighbors = if connected.contains(&v) {
+                g.neighbors(&v)
+            } else {
+                vec![]
+            };
+            (v, neighbors)
+        })
+        .collect()
+}
+
+/// Returns a separator of the subgraph induced by `vertices` using the embedding
+/// given by the rotation system.
+pub(crate) fn separate<V: Hash + Eq + Clone>(
+    rotation: &HashMap<V, Vec<V>>,
+    vertices: &HashSet<V>,
+) -> Separator<V> {
+    let n = vertices.len();
+    let separator = components(rotation, vertices, &HashSet::new())
+        .into_iter()
+        .find(|component| 3 * component.len() > 2 * n)
+        .map(|component| separate_component(rotation, &component, n))
+        .unwrap_or_default();
+
+    partition(rotation, vertices, separator)
+}
+
+// Returns a separator of size at most `2 * sqrt(2m)` of a component with `m` vertices whose
+// removal leaves no component with more than `2n/3` vertices
+fn separate_component<V: Hash + Eq + Clone>(
+    rotation: &HashMap<V, Vec<V>>,
+    component: &HashSet<V>,
+    n: usize,
+) -> HashSet<V> {
+    let m = component.len();
+    let root = component.iter().next().unwrap().clone();
+    let tree = BfsTree::compute(rotation, component, root);
+    let levels = &tree.levels;
+    let level_size = |i: isize| {
+        if i < 0 || i as usize >= levels.len() {
+            0
+        } else {
+            levels[i as usize].len()
+        }
+    };
+
+    // the middle level and the two small levels around it
+    let mut l1 = 0;
+    let mut count = levels[0].len();
+
+    while 2 * count < m {
+        l1 += 1;
+        count += levels[l1 as usize].len();
+    }
+
+    let l0 = (-1..=l1)
+        .min_by_key(|i| level_size(*i) + 2 * (l1 - i) as usize)
+        .unwrap();
+    let l2 = (l1 + 1..=levels.len() as isize)
+        .min_by_key(|i| level_size(*i) + 2 * (i - l1 - 1) as usize)
+        .unwrap();
+
+    // the levels below l0 and above l2 contain less than m/2 vertices each
+    let mut separator: HashSet<V> = HashSet::new();
+
+    for i in [l0, l2] {
+        if level_size(i) > 0 {
+            separator.extend(levels[i as usize].iter().cloned());
+        }
+    }
+
+    let middle: HashSet<V> = component
+        .iter()
+        .filter(|v| (l0 + 1..l2).contains(&(tree.level[*v] as isize)))
+        .cloned()
+        .collect();
+
+    if components(rotation, &middle, &HashSet::new())
+        .iter()
+        .all(|component| 3 * component.len() <= 2 * n)
+    {
+        return separator;
+    }
+
+    // the levels up to l0 are contracted into the root of a tree of depth l2 - l0 - 1 spanning
+    // the middle levels, which are split by one of its fundamental cycles
+    separator.extend(
+        Plane::new(rotation, &tree, &middle, l0)
+            .balanced_cycle()
+            .into_iter()
+            .flatten(),
+    );
+
+    separator
+}
+
+/// Splits the vertices without the separator into two parts of at most `2n/3` vertices
+/// each, which requires every component to have at most `2n/3` vertices.
+fn partition<V: Hash + Eq + Clone>(
+    rotation: &HashMap<V, Vec<V>>,
+    vertices: &HashSet<V>,
+    separator: HashSet<V>,
+) -> Separator<V> {
+    let n = vertices.len();
+    let mut components = components(rotation, vertices, &separator);
+    components.sort_by_key(|component| std::cmp::Reverse(component.len()));
+
+    let mut a = HashSet::new();
+    let mut b = HashSet::new();
+
+    // once a has n/3 vertices the rest has at most 2n/3, before that every component added to
+    // a is smaller than the first one or than n/3
+    for component in components {
+        if 3 * a.len() < n {
+            a.extend(component);
+        } else {
+            b.extend(component);
+        }
+    }
+
+    Separator { separator, a, b }
+}
+
+/// Returns the connected components of the graph induced by `vertices` without `removed`.
+pub(crate) fn components<V: Hash + Eq + Clone>(
+    rotation: &HashMap<V, Vec<V>>,
+    vertices: &HashSet<V>,
+    removed: &HashSet<V>,
+) -> Vec<HashSet<V>> {
+    let mut visited: HashSet<V> = removed.clone();
+    let mut components = vec![];
+
+    for start in vertices {
+        if !visited.insert(start.clone()) {
+            continue;
+        }
+
+        let mut component = HashSet::from([start.clone()]);
+        let mut stack = vec![start.clone()];
+
+        while let Some(v) = stack.pop() {
+            for u in &rotation[&v] {
+                if vertices.contains(u) && visited.insert(u.clone()) {
+                    component.insert(u.clone());
+                    stack.push(u.clone());
+                }
+            }
+        }
+
+        components.push(component);
+    }
+
+    components
+}
+
+struct BfsTree<V> {
+    parent: HashMap<V, V>,
+    level: HashMap<V, usize>,
+    levels: Vec<Vec<V>>,
+}
+
+impl<V: Hash + Eq + Clone> BfsTree<V> {
+    fn compute(rotation: &HashMap<V, Vec<V>>, vertices: &HashSet<V>, root: V) -> Self {
+        let mut parent = HashMap::new();
+        let mut level = HashMap::from([(root.clone(), 0)]);
+        let mut levels = vec![];
+        let mut queue = VecDeque::from([root]);
+
+        while let Some(v) = queue.pop_front() {
+            let i = level[&v];
+
+            if levels.len() <= i {
+                levels.push(vec![]);
+            }
+
+            levels[i].push(v.clone());
+
+            for u in &rotation[&v] {
+                if vertices.contains(u) && !level.contains_key(u) {
+                    level.insert(u.clone(), i + 1);
+                    parent.insert(u.clone(), v.clone());
+                    queue.push_back(u.clone());
+                }
+            }
+        }
+
+        BfsTree {
+            parent,
+            level,
+            levels,
+        }
+    }
+}
+
+/// A compact copy of a connected subgraph spanned by the bfs tree, in which the levels up to
+/// `l0` may be contracted into the root `None`. Vertex `0` is the root and the darts `2e` and
+/// `2e + 1` belong to edge `e`.
+struct Plane<V> {
+    vertices: Vec<Option<V>>,
+    parent: Vec<usize>,
+    depth: Vec<usize>,
+    head: Vec<usize>,
+    rotation: Vec<Vec<usize>>,
+    position: Vec<usize>,
+}
+
+impl<V: Hash + Eq + Clone> Plane<V> {
+    fn new(
+        rotation: &HashMap<V, Vec<V>>,
+        tree: &BfsTree<V>,
+        middle: &HashSet<V>,
+        l0: isize,
+    ) -> Self {
+        let contracted = l0 >= 0;
+        let mut vertices = if contracted { vec![None] } else { vec![] };
+        vertices.extend(
+            tree.levels
+                .iter()
+                .flatten()
+                .filter(|v| middle.contains(v))
+                .cloned()
+                .map(Some),
+        );
+
+        let index: HashMap<V, usize> = vertices
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.clone().map(|v| (v, i)))
+            .collect();
+        let parent = vertices
+            .iter()
+            .map(|v| match v.as_ref().and_then(|v| tree.parent.get(v)) {
+                Some(p) => index.get(p).copied().unwrap_or(0),
+                None => 0,
+            })
+            .collect();
+        let depth = vertices
+            .iter()
+            .map(|v| v.as_ref().map_or(0, |v| tree.level[v] - l0.max(0) as usize))
+            .collect();
+
+        // only the tree edges between the middle levels and l0 are kept, so the contraction
+        // leaves a simple graph
+        let neighbors = vertices
+            .iter()
+            .map(|v| match v {
+                Some(v) => rotation[v]
+                    .iter()
+                    .filter_map(|u| match index.get(u) {
+                        Some(&j) => Some(j),
+                        None => (tree.parent.get(v) == Some(u)).then_some(0),
+                    })
+                    .collect(),
+                None => contracted_rotation(rotation, tree, &index, l0),
+            })
+            .collect::<Vec<Vec<_>>>();
+
+        let mut edges = HashMap::new();
+        let mut head = vec![];
+        let mut position = vec![];
+        let rotation = neighbors
+            .iter()
+            .enumerate()
+            .map(|(i, neighbors)| {
+                neighbors
+                    .iter()
+                    .enumerate()
+                    .map(|(k, &j)| {
+                        let e = *edges.entry((i.min(j), i.max(j))).or_insert_with(|| {
+                            head.extend([i.max(j), i.min(j)]);
+                            position.extend([0, 0]);
+                            head.len() / 2 - 1
+                        });
+                        let d = 2 * e + usize::from(i > j);
+                        position[d] = k;
+                        d
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Plane {
+            vertices,
+            parent,
+            depth,
+            head,
+            rotation,
+            position,
+        }
+    }
+
+    fn tail(&self, d: usize) -> usize {
+        self.head[d ^ 1]
+    }
+
+    // Returns the dart following `d` on its face
+    fn next(&self, d: usize) -> usize {
+        let rotation = &self.rotation[self.head[d]];
+        rotation[(self.position[d ^ 1] + 1) % rotation.len()]
+    }
+
+    fn is_tree_edge(&self, u: usize, v: usize) -> bool {
+        u != v && (self.parent[u] == v || self.parent[v] == u)
+    }
+
+    // Returns a fundamental cycle of the tree in a triangulation of the graph, which leaves at
+    // most 2n/3 vertices on both sides
+    fn balanced_cycle(&self) -> Vec<Option<V>> {
+        let n = self.vertices.len();
+
+        if n < 3 {
+            return self.vertices.clone();
+        }
+
+        // the triangles are connected across the edges not in the tree, which form a spanning
+        // tree of the dual graph
+        let (triangles, crossings) = self.triangulate();
+        let mut adjacent = vec![vec![]; triangles];
+
+        for (c, &(s, t, _, _)) in crossings.iter().enumerate() {
+            adjacent[s].push((t, c));
+            adjacent[t].push((s, c));
+        }
+
+        let mut order = vec![0];
+        let mut parent = vec![None; triangles];
+        let mut visited = vec![false; triangles];
+        visited[0] = true;
+
+        for i in 0..triangles {
+            for &(t, c) in &adjacent[order[i]] {
+                if !visited[t] {
+                    visited[t] = true;
+                    parent[t] = Some(c);
+                    order.push(t);
+                }
+            }
+        }
+
+        let mut size = vec![1; triangles];
+        for &t in order.iter().rev() {
+            if let Some(c) = parent[t] {
+                let (s, r, _, _) = crossings[c];
+                let other = if s == t { r } else { s };
+                size[other] += size[t];
+            }
+        }
+
+        // a disc of f triangles bounded by a cycle of length k contains (f - k + 2) / 2 vertices
+        let up = self.ancestors();
+        let (u, v, lca) = order[1..]
+            .iter()
+            .map(|&t| {
+                let (_, _, u, v) = crossings[parent[t].unwrap()];
+                let lca = self.lca(&up, u, v);
+                let k = self.depth[u] + self.depth[v] - 2 * self.depth[lca] + 1;
+                (size[t], u, v, lca, k)
+            })
+            .find(|&(f, _, _, _, k)| {
+                3 * (f + 2 - k) <= 4 * n && 3 * (triangles - f + 2 - k) <= 4 * n
+            })
+            .map(|(_, u, v, lca, _)| (u, v, lca))
+            .expect("a triangulation has a balanced fundamental cycle");
+
+        let mut cycle = vec![u];
+        while *cycle.last().unwrap() != lca {
+            cycle.push(self.parent[*cycle.last().unwrap()]);
+        }
+
+        let mut w = v;
+        let mut path = vec![];
+        while w != lca {
+            path.push(w);
+            w = self.parent[w];
+        }
+
+        cycle
+            .into_iter()
+            .chain(path.into_iter().rev())
+            .map(|i| self.vertices[i].clone())
+            .collect()
+    }
+
+    // Returns the number of triangles of a triangulation of every face from a vertex occurring
+    // once on its boundary, and for every edge not in the tree the two triangles it separates
+    // together with its endpoints
+    fn triangulate(&self) -> (usize, Vec<(usize, usize, usize, usize)>) {
+        let mut triangle = vec![usize::MAX; self.head.len()];
+        let mut occurrences = vec![0; self.vertices.len()];
+        let mut crossings = vec![];
+        let mut triangles = 0;
+
+        for start in 0..self.head.len() {
+            if triangle[start] != usize::MAX {
+                continue;
+            }
+
+            let mut face = vec![start];
+            while self.next(*face.last().unwrap()) != start {
+                face.push(self.next(*face.last().unwrap()));
+            }
+
+            for &d in &face {
+                occurrences[self.tail(d)] += 1;
+            }
+            let first = face
+                .iter()
+                .position(|&d| occurrences[self.tail(d)] == 1)
+                .expect("a face boundary contains a vertex once");
+            for &d in &face {
+                occurrences[self.tail(d)] = 0;
+            }
+            face.rotate_left(first);
+
+            // the fan triangles (t0, tj, tj+1) for 0 < j < k - 1
+            let k = face.len();
+            for (j, &d) in face.iter().enumerate() {
+                triangle[d] = triangles + j.saturating_sub(1).min(k - 3);
+            }
+            for j in 1..k - 2 {
+                crossings.push((
+                    triangles + j - 1,
+                    triangles + j,
+                    self.tail(face[0]),
+                    self.tail(face[j + 1]),
+                ));
+            }
+
+            triangles += k - 2;
+        }
+
+        for e in 0..self.head.len() / 2 {
+            let (u, v) = (self.tail(2 * e), self.head[2 * e]);
+
+            if !self.is_tree_edge(u, v) {
+                crossings.push((triangle[2 * e], triangle[2 * e + 1], u, v));
+            }
+        }
+
+        (triangles, crossings)
+    }
+
+    // Returns the 2^i-th ancestors of every vertex
+    fn ancestors(&self) -> Vec<Vec<usize>> {
+        let mut up = vec![self.parent.clone()];
+
+        while 1 << up.len() < self.vertices.len() {
+            let last = up.last().unwrap();
+            up.push(last.iter().map(|&p| last[p]).collect());
+        }
+
+        up
+    }
+
+    fn lca(&self, up: &[Vec<usize>], mut u: usize, mut v: usize) -> usize {
+        if self.depth[u] < self.depth[v] {
+            std::mem::swap(&mut u, &mut v);
+        }
+
+        let diff = self.depth[u] - self.depth[v];
+        for (i, up) in up.iter().enumerate() {
+            if diff >> i & 1 == 1 {
+                u = up[u];
+            }
+        }
+
+        if u == v {
+            return u;
+        }
+
+        for up in up.iter().rev() {
+            if up[u] != up[v] {
+                u = up[u];
+                v = up[v];
+            }
+        }
+
+        self.parent[u]
+    }
+}
+
+// Returns the neighbors of the root obtained by contracting the levels up to `l0`, which are
+// met by an euler tour around the bfs tree of these levels
+fn contracted_rotation<V: Hash + Eq + Clone>(
+    rotation: &HashMap<V, Vec<V>>,
+    tree: &BfsTree<V>,
+    index: &HashMap<V, usize>,
+    l0: isize,
+) -> Vec<usize> {
+    let root = &tree.levels[0][0];
+    let mut neighbors = vec![];
+    let mut stack = vec![(root, 0, rotation[root].len())];
+
+    while let Some((v, next, remaining)) = stack.pop() {
+        if remaining == 0 {
+            continue;
+        }
+
+        let u = &rotation[v][next % rotation[v].len()];
+        stack.push((v, next + 1, remaining - 1));
+
+        if tree.parent.get(u) != Some(v) {
+            continue;
+        }
+
+        if tree.level[u] as isize <= l0 {
+            // the tour continues around u after the edge back to v
+            let back = rotation[u].iter().position(|w| w == v).unwrap();
+            stack.push((u, back + 1, rotation[u].len() - 1));
+        } else {
+            neighbors.push(index[u]);
+        }
+    }
+
+    neighbors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        cycle_separator, planar_separator, rotation_system, shortest_path_separator, Separator,
+    };
+    use crate::data_structure::{
+        graph_dcel::GraphDCEL,
+        link_graph::{LinkGraph, LinkVertex},
+    };
+    use crate::embedding::{index::Embedding, maximal_planar::index::MaximalPlanar};
+    use crate::generation::{
+        families::grid,
+        planar::{generate, generate_with_edges},
+    };
+    use std::collections::{HashMap, HashSet, VecDeque};
+
+    fn bfs(lg: &LinkGraph, start: &LinkVertex) -> HashMap<LinkVertex, usize> {
+        let mut distance = HashMap::from([(start.clone(), 0)]);
+        let mut queue = VecDeque::from([start.clone()]);
+
+        while let Some(v) = queue.pop_front() {
+            for u in lg.neighbors(&v) {
+                if !distance.contains_key(&u) {
+                    distance.insert(u.clone(), distance[&v] + 1);
+                    queue.push_back(u);
+                }
+            }
+        }
+
+        distance
+    }
+
+    fn assert_separates(lg: &LinkGraph, separator: &Separator<LinkVertex>) {
+        let n = lg.vertex_count();
+
+        assert_eq!(
+            separator.a.len() + separator.b.len() + separator.separator.len(),
+            n
+        );
+        assert!(3 * separator.a.len() <= 2 * n);
+        assert!(3 * separator.b.len() <= 2 * n);
+
+        for v in &separator.a {
+            assert!(!separator.separator.contains(v));
+            assert!(lg.neighbors(v).iter().all(|u| !separator.b.contains(u)));
+        }
+    }
+
+    #[test]
+    fn single_vertex() {
+        let mut lg = LinkGraph::new();
+        let lv = lg.new_vertex();
+        let separator = planar_separator(&lg);
+
+        assert_eq!(separator.separator, HashSet::from([lv]));
+    }
+
+    #[test]
+    fn random() {
+        for n in (4..100).chain([250, 500]) {
+            let lg = MaximalPlanar::embed(generate(n, Some(n as u64)).to_pet_graph());
+            let separator = planar_separator(&lg);
+
+            assert_separates(&lg, &separator);
+            assert!(separator.separator.len() as f64 <= 4.0 * (n as f64).sqrt() + 2.0);
+        }
+    }
+
+    #[test]
+    fn cycle() {
+        for n in 4..60 {
+            let lg = MaximalPlanar::embed(generate(n, Some(n as u64)).to_pet_graph());
+            let (cycle, separator) = cycle_separator(&lg);
+
+            assert_separates(&lg, &separator);
+            assert_eq!(cycle.iter().collect::<HashSet<_>>().len(), cycle.len());
+
+            for (i, v) in cycle.iter().enumerate() {
+                let u = &cycle[(i + 1) % cycle.len()];
+                assert!(cycle.len() < 3 || lg.neighbors(v).contains(u));
+            }
+        }
+    }
+
+    #[test]
+    fn shortest_paths() {
+        for n in (4..60).chain([300]) {
+            let lg = generate_with_edges(n, n + n / 2, Some(n as u64)).to_link_graph();
+            let (paths, separator) =
+                shortest_path_separator(&rotation_system(&lg), &lg.get_vertexes().collect());
+
+            assert_separates(&lg, &separator);
+            assert_eq!(paths.len(), 2);
+
+            for path in paths {
+                let distances = bfs(&lg, &path[0]);
+
+                for (i, v) in path.iter().enumerate() {
+                    assert_eq!(distances[v], i);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sparse() {
+        // trees and sparse graphs require the cycle in the contracted middle levels
+        for n in (4..80).chain([300, 1000]) {
+            for m in [n - 1, n + n / 4, 2 * n - 3] {
+                let lg = generate_with_edges(n, m, Some((n * m) as u64)).to_link_graph();
+                let separator = planar_separator(&lg);
+
+                assert_separates(&lg, &separator);
+                assert!(separator.separator.len() as f64 <= 2.0 * (2.0 * n as f64).sqrt() + 1.0);
+            }
+        }
+
+        for (width, height) in [(2, 30), (10, 10), (40, 25)] {
+            let lg = grid(width, height).to_link_graph();
+            let separator = planar_separator(&lg);
+
+            let n = width * height;
+
+            assert_separates(&lg, &separator);
+            assert!(separator.separator.len() as f64 <= 2.0 * (2.0 * n as f64).sqrt() + 1.0);
+        }
+    }
+
+    #[test]
+    fn sparse_cycle() {
+        for n in (4..60).chain([500]) {
+            let lg = generate_with_edges(n, n - 1 + n / 3, Some(n as u64)).to_link_graph();
+            let (cycle, separator) = cycle_separator(&lg);
+
+            assert_separates(&lg, &separator);
+            assert_eq!(cycle.iter().collect::<HashSet<_>>().len(), cycle.len());
+
+            // only the edge closing the cycle may be missing in the graph
+            for i in 1..cycle.len() {
+                assert!(lg.neighbors(&cycle[i - 1]).contains(&cycle[i]));
+            }
+        }
+    }
+
+    #[test]
+    fn large() {
+        let n = 50_000;
+        let lg = generate_with_edges(n, 2 * n, Some(1)).to_link_graph();
+        let separator = planar_separator(&lg);
+
+        assert_separates(&lg, &separator);
+        assert!(separator.separator.len() as f64 <= 2.0 * (2.0 * n as f64).sqrt() + 1.0);
+    }
+}
@@ -0,0 +1,198 @@
+//! Contains an alternative PTAS for planar graphs based on the planar separator theorem.
+//!
+//! The graph is split recursively by planar separators until every piece has at most
+//! `(2 / eps)^2` vertices. The pieces are solved exactly and the solutions are combined.
+//! Since the separators of all levels contain `O(n * eps)` vertices, the solution is close
+//! to optimal for problems like maximum independent set.
+//!
+//! ```rust
+//! use graph_algo_ptas::generation::planar::generate;
+//! use graph_algo_ptas::data_structure::{graph_dcel::Vertex, link_graph::LinkGraph};
+//! use graph_algo_ptas::embedding::maximal_planar::index::MaximalPlanar;
+//! use graph_algo_ptas::algorithm::separator_ptas::separator_ptas;
+//! use graph_algo_ptas::algorithm::dynamic_programming::solve::DpProblem;
+//!
+//! let input = generate(100, None).to_pet_graph();
+//! let (graph, vertexes): (LinkGraph, _) = MaximalPlanar::embed_with_mapping(input);
+//! let sol = separator_ptas(&graph, &DpProblem::max_independent_set(), 0.5);
+//!
+//! // the nodes of the input graph in the solution
+//! let nodes = vertexes.iter().filter(|(_, v)| sol.contains(&v.get_id())).map(|(n, _)| *n);
+//! assert_eq!(nodes.count(), sol.len());
+//! ```
+
+use super::{
+    dynamic_programming::solve::{dp_solve_hashmap_graph, DpObjective, DpProblem},
+    separator::{rotation_system, separate},
+};
+use crate::{
+    data_structure::{
+        graph_dcel::{Dart, Face, GraphDCEL, Vertex},
+        list_graph::{ListGraph, NodeId},
+    },
+    utils::convert::induced_hash_map_graph,
+};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Calculates an approximate solution for the given problem on the embedded input graph.
+///
+/// Returns the ids of the vertices in the solution.
+pub fn separator_ptas<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    graph: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+    prob: &DpProblem,
+    eps: f64,
+) -> HashSet<usize> {
+    let max_piece_size = (2.0 / eps).powi(2).ceil() as usize;
+    let rotation = rotation_system(graph);
+    let mut pieces = vec![];
+    let mut separators = HashSet::new();
+
+    split(
+        &rotation,
+        graph.get_vertexes().collect(),
+        max_piece_size,
+        &mut pieces,
+        &mut separators,
+    );
+
+    let mut sol = HashSet::new();
+
+    for piece in pieces {
+        sol.extend(dp_solve_hashmap_graph(
+            &induced_hash_map_graph(&rotation, &piece),
+            None,
+            prob,
+        ));
+    }
+
+    if prob.objective == DpObjective::Minimize {
+        sol.extend(separators.iter().map(|v| v.get_id()));
+    }
+
+    sol
+}
+
+/// Calculates an approximate solution for the given problem on the planar graph embedded by
+/// the rotation system of the ListGraph, see [separator_ptas]. The graph does not have to be
+/// triangulated.
+///
+/// Returns the node ids of the solution.
+pub fn separator_ptas_list_graph(graph: &ListGraph, prob: &DpProblem, eps: f64) -> HashSet<NodeId> {
+    separator_ptas(&graph.to_link_graph(), prob, eps)
+}
+
+fn split<V: Hash + Eq + Clone>(
+    rotation: &HashMap<V, Vec<V>>,
+    vertices: HashSet<V>,
+    max_piece_size: usize,
+    pieces: &mut Vec<HashSet<V>>,
+    separators: &mut HashSet<V>,
+) {
+    if vertices.is_empty() {
+        return;
+    }
+
+    if vertices.len() <= max_piece_size {
+        pieces.push(vertices);
+        return;
+    }
+
+    let separator = separate(rotation, &vertices);
+    separators.extend(separator.separator);
+    split(rotation, separator.a, max_piece_size, pieces, separators);
+    split(rotation, separator.b, max_piece_size, pieces, separators);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{separator_ptas, separator_ptas_list_graph};
+    use crate::{
+        algorithm::dynamic_programming::solve::DpProblem,
+        embedding::{index::Embedding, maximal_planar::index::MaximalPlanar},
+        generation::planar::{generate, generate_with_edges},
+        utils::{
+            convert::{self, dcel_to_hash_map_graph, list_graph_to_hash_map_graph},
+            max_independent_set::{brute_force_max_independent_set, is_independent_set},
+            min_vertex_cover::{brute_force_min_vertex_cover, is_vertex_cover},
+        },
+    };
+
+    #[test]
+    fn max_independent_set_random() {
+        for n in (4..30).chain([100, 300]) {
+            let pet_graph = generate(n, Some(n as u64)).to_pet_graph();
+            let lg = MaximalPlanar::embed(pet_graph.clone());
+            let graph = dcel_to_hash_map_graph(&lg);
+            let sol = separator_ptas(&lg, &DpProblem::max_independent_set(), 0.5);
+
+            assert!(is_independent_set(&graph, &sol));
+
+            // a single piece is solved exactly
+            if n <= 15 {
+                assert!(
+                    sol.len()
+                        == brute_force_max_independent_set(&convert::to_hash_map_graph(&pet_graph))
+                            .len()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn min_vertex_cover_random() {
+        for n in (4..30).chain([100, 300]) {
+            let pet_graph = generate(n, Some(n as u64)).to_pet_graph();
+            let lg = MaximalPlanar::embed(pet_graph.clone());
+            let graph = dcel_to_hash_map_graph(&lg);
+            let sol = separator_ptas(&lg, &DpProblem::min_vertex_cover(), 0.5);
+
+            assert!(is_vertex_cover(&graph, &sol));
+
+            if n <= 15 {
+                assert!(
+                    sol.len()
+                        == brute_force_min_vertex_cover(&convert::to_hash_map_graph(&pet_graph))
+                            .len()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn small_pieces() {
+        let lg = MaximalPlanar::embed(generate(60, Some(1)).to_pet_graph());
+        let graph = dcel_to_hash_map_graph(&lg);
+        let sol = separator_ptas(&lg, &DpProblem::max_independent_set(), 1.0);
+
+        assert!(is_independent_set(&graph, &sol));
+        assert!(!sol.is_empty());
+    }
+
+    #[test]
+    fn list_graph() {
+        for (n, m) in [(4, 3), (10, 9), (10, 15), (12, 20), (60, 70), (60, 150)] {
+            let graph = generate_with_edges(n, m, Some(n as u64));
+            let hash_map_graph = list_graph_to_hash_map_graph(&graph);
+            let sol = separator_ptas_list_graph(&graph, &DpProblem::max_independent_set(), 0.5);
+
+            assert!(is_independent_set(&hash_map_graph, &sol));
+            if n <= 15 {
+                assert_eq!(
+                    sol.len(),
+                    brute_force_max_independent_set(&hash_map_graph).len()
+                );
+            }
+
+            let sol = separator_ptas_list_graph(&graph, &DpProblem::min_vertex_cover(), 0.5);
+            assert!(is_vertex_cover(&hash_map_graph, &sol));
+        }
+    }
+}
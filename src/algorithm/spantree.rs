@@ -1,9 +1,7 @@
 //! Contains implementation of a span tree
-use crate::data_structure::{
-    graph_dcel::GraphDCEL,
-    link_graph::{LinkDart, LinkFace, LinkGraphIter, LinkVertex},
-};
+use crate::data_structure::graph_dcel::{Dart, Face, GraphDCEL, Vertex};
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
 
 /// The structure containing the span tree (downwards from root to leaves and upwards from leaf to root)
 pub struct Span<T> {
@@ -15,18 +13,17 @@ pub struct Span<T> {
     pub upwards: HashMap<T, T>,
 }
 
-impl Span<LinkVertex> {
+impl<V: Vertex + Eq + Hash + Clone> Span<V> {
     /// Returns a span tree beginning with root
-    pub fn compute(
-        g: &impl GraphDCEL<
-            LinkVertex,
-            LinkDart,
-            LinkFace,
-            LinkGraphIter<LinkVertex>,
-            LinkGraphIter<LinkDart>,
-            LinkGraphIter<LinkFace>,
-        >,
-        root: LinkVertex,
+    pub fn compute<
+        D: Dart,
+        F: Face,
+        VI: Iterator<Item = V>,
+        DI: Iterator<Item = D>,
+        FI: Iterator<Item = F>,
+    >(
+        g: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+        root: V,
     ) -> Self {
         assert!(g.get_vertexes().count() > 1);
         let mut queue = VecDeque::new();
@@ -0,0 +1,589 @@
+//! Contains algorithms for subgraph isomorphism and pattern counting on planar graphs.
+//!
+//! Occurrences are non-induced subgraphs of the host graph isomorphic to the pattern.
+//! Following Eppstein, the host graph is split by a breadth first search into windows
+//! of `diam(pattern) + 1` consecutive levels. Every occurrence lies completely inside
+//! at least one window, so the occurrences are counted by dynamic programming on a nice
+//! tree decomposition of every window.
+//!
+//! # Running time
+//!
+//! Let the host graph have `n` vertices and the pattern `k` vertices and diameter `d`.
+//! Every vertex lies in at most `d + 1` windows and a window whose decomposition has width
+//! `w` is solved with at most `(k + 1)^(w + 1) * 2^k` table entries per bag, so the total
+//! running time is `O((d + 1) * n * (k + 1)^(w + 1) * 4^k)`, where `w` is the largest width
+//! of the decompositions of the windows.
+//!
+//! A window of a planar graph has treewidth O(d), but the host graph is not embedded, so
+//! its decomposition is computed by the min-degree heuristic, which does not guarantee this
+//! width. `w` is only bounded by the size of the window, so the running time is linear in
+//! `n` only if the heuristic finds decompositions of width O(d).
+//!
+//! ```rust
+//! use graph_algo_ptas::generation::planar::generate;
+//! use graph_algo_ptas::algorithm::subgraph_isomorphism::count_pattern;
+//! use petgraph::stable_graph::StableGraph;
+//!
+//! let graph = generate(50, Some(1)).to_pet_graph();
+//! let triangle = StableGraph::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+//! let triangles = count_pattern(&triangle, &graph).unwrap();
+//! ```
+
+use super::{
+    dynamic_programming::utils::remap_vertices,
+    elimination_ordering::min_degree_decomposition,
+    nice_tree_decomposition::{get_children, NiceTdNodeType, NiceTreeDecomposition},
+    ptas::get_levels,
+};
+use crate::utils::convert::UndirectedGraph;
+use arboretum_td::graph::{BaseGraph, HashMapGraph, MutableGraph};
+use petgraph::{algo::kosaraju_scc, stable_graph::NodeIndex};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    fmt,
+};
+
+/// Maps the vertices of the pattern to the vertices of the host graph.
+pub type PatternMapping = HashMap<usize, usize>;
+
+/// The reasons why a graph cannot be used as pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternError {
+    /// The pattern has no vertices
+    Empty,
+    /// The pattern has more than 64 vertices
+    TooLarge,
+    /// The pattern is not connected
+    Disconnected,
+    /// The pattern contains a loop
+    Loop,
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::Empty => write!(f, "the pattern is empty"),
+            PatternError::TooLarge => write!(f, "the pattern has more than 64 vertices"),
+            PatternError::Disconnected => write!(f, "the pattern is not connected"),
+            PatternError::Loop => write!(f, "the pattern contains a loop"),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+/// Returns true if the pattern occurs as a subgraph of the host graph.
+///
+/// The pattern has to be connected, the host graph is expected to be planar. See the
+/// [module documentation](self) for the running time.
+pub fn contains_pattern(
+    pattern: &UndirectedGraph,
+    host: &UndirectedGraph,
+) -> Result<bool, PatternError> {
+    Ok(count_embeddings(&PatternGraph::new(pattern)?, host) > 0)
+}
+
+/// Returns the number of subgraphs of the host graph which are isomorphic to the pattern.
+///
+/// The pattern has to be connected, the host graph is expected to be planar. See the
+/// [module documentation](self) for the running time.
+pub fn count_pattern(
+    pattern: &UndirectedGraph,
+    host: &UndirectedGraph,
+) -> Result<usize, PatternError> {
+    let pattern_graph = PatternGraph::new(pattern)?;
+    Ok(count_embeddings(&pattern_graph, host) / count_embeddings(&pattern_graph, pattern))
+}
+
+/// Returns one mapping for every subgraph of the host graph which is isomorphic to the pattern.
+///
+/// The pattern has to be connected, the host graph is expected to be planar. See the
+/// [module documentation](self) for the running time.
+pub fn list_pattern(
+    pattern: &UndirectedGraph,
+    host: &UndirectedGraph,
+) -> Result<Vec<PatternMapping>, PatternError> {
+    let pattern_graph = PatternGraph::new(pattern)?;
+    let mut occurrences = HashSet::new();
+    let mut mappings = vec![];
+
+    for_each_window(&pattern_graph, host, |window, new_vertices| {
+        let embeddings: Vec<Vec<(usize, usize)>> = solve_window(&pattern_graph, window);
+
+        for embedding in embeddings {
+            if !embedding.iter().any(|(v, _)| new_vertices.contains(v)) {
+                continue;
+            }
+
+            let mapping: HashMap<usize, usize> = embedding
+                .iter()
+                .map(|(v, p)| (pattern_graph.vertices[*p], *v))
+                .collect();
+            let edges: BTreeSet<(usize, usize)> = pattern_graph
+                .edges
+                .iter()
+                .map(|(p, q)| {
+                    let u = mapping[&pattern_graph.vertices[*p]];
+                    let v = mapping[&pattern_graph.vertices[*q]];
+                    (u.min(v), u.max(v))
+                })
+                .collect();
+            let vertices: BTreeSet<usize> = mapping.values().copied().collect();
+
+            if occurrences.insert((vertices, edges)) {
+                mappings.push(mapping);
+            }
+        }
+    });
+
+    Ok(mappings)
+}
+
+/// Returns the number of injective mappings from the pattern into the host graph
+/// which map edges to edges.
+fn count_embeddings(pattern: &PatternGraph, host: &UndirectedGraph) -> usize {
+    let mut count = 0;
+
+    // counts the embeddings using at least one vertex of the first level of the window
+    for_each_window(pattern, host, |window, new_vertices| {
+        let mut remainder = window.clone();
+
+        for v in new_vertices {
+            remainder.remove_vertex(*v);
+        }
+
+        count += solve_window::<usize>(pattern, window);
+        count -= solve_window::<usize>(pattern, &remainder);
+    });
+
+    count
+}
+
+struct PatternGraph {
+    vertices: Vec<usize>,
+    edges: Vec<(usize, usize)>,
+    neighbors: Vec<u64>,
+    diameter: usize,
+}
+
+impl PatternGraph {
+    fn new(pattern: &UndirectedGraph) -> Result<Self, PatternError> {
+        if pattern.node_count() == 0 {
+            return Err(PatternError::Empty);
+        }
+        if pattern.node_count() > 64 {
+            return Err(PatternError::TooLarge);
+        }
+        if kosaraju_scc(pattern).len() != 1 {
+            return Err(PatternError::Disconnected);
+        }
+
+        let vertices: Vec<usize> = pattern.node_indices().map(|v| v.index()).collect();
+        let index: HashMap<usize, usize> =
+            vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+        let mut neighbors = vec![0; vertices.len()];
+        let mut edges = vec![];
+
+        for e in pattern.edge_indices() {
+            let (u, v) = pattern.edge_endpoints(e).unwrap();
+            let (p, q) = (index[&u.index()], index[&v.index()]);
+            if p == q {
+                return Err(PatternError::Loop);
+            }
+            neighbors[p] |= 1 << q;
+            neighbors[q] |= 1 << p;
+            edges.push((p, q));
+        }
+
+        let diameter = (0..vertices.len())
+            .map(|p| {
+                let mut dist = vec![usize::MAX; vertices.len()];
+                let mut queue = VecDeque::from([p]);
+                dist[p] = 0;
+
+                while let Some(q) = queue.pop_front() {
+                    for r in 0..vertices.len() {
+                        if neighbors[q] & (1 << r) != 0 && dist[r] == usize::MAX {
+                            dist[r] = dist[q] + 1;
+                            queue.push_back(r);
+                        }
+                    }
+                }
+
+                dist.into_iter().max().unwrap()
+            })
+            .max()
+            .unwrap();
+
+        Ok(PatternGraph {
+            vertices,
+            edges,
+            neighbors,
+            diameter,
+        })
+    }
+
+    fn full(&self) -> u64 {
+        u64::MAX >> (64 - self.vertices.len())
+    }
+}
+
+/// Calls `handle_window` for every window of `diameter + 1` consecutive bfs levels of every
+/// connected component together with the vertices of the first level of the window.
+fn for_each_window(
+    pattern: &PatternGraph,
+    host: &UndirectedGraph,
+    mut handle_window: impl FnMut(&HashMapGraph, &HashSet<usize>),
+) {
+    let mut visited = HashSet::new();
+
+    for start in host.node_indices() {
+        if visited.contains(&start) {
+            continue;
+        }
+
+        let levels = get_levels(host, &[start]);
+        visited.extend(levels.iter().flatten().copied());
+
+        for i in 0..levels.len() {
+            let mut window = HashMapGraph::new();
+
+            for level in levels.iter().skip(i).take(pattern.diameter + 1) {
+                for v in level {
+                    window.add_vertex(v.index());
+                }
+            }
+
+            for v in window.vertices().collect::<Vec<_>>() {
+                for u in host.neighbors(NodeIndex::new(v)) {
+                    if window.has_vertex(u.index()) {
+                        window.add_edge(v, u.index());
+                    }
+                }
+            }
+
+            let new_vertices = levels[i].iter().map(|v| v.index()).collect();
+            handle_window(&window, &new_vertices);
+        }
+    }
+}
+
+/// A value which is accumulated over all partial embeddings represented by one table entry.
+trait Embeddings: Clone {
+    fn empty() -> Self;
+    fn single() -> Self;
+    fn merge(&mut self, other: &Self);
+    fn combine(&self, other: &Self) -> Self;
+    fn assign(&mut self, vertex: usize, pattern_vertex: usize);
+}
+
+impl Embeddings for usize {
+    fn empty() -> Self {
+        0
+    }
+
+    fn single() -> Self {
+        1
+    }
+
+    fn merge(&mut self, other: &Self) {
+        *self += other;
+    }
+
+    fn combine(&self, other: &Self) -> Self {
+        self * other
+    }
+
+    fn assign(&mut self, _: usize, _: usize) {}
+}
+
+impl Embeddings for Vec<Vec<(usize, usize)>> {
+    fn empty() -> Self {
+        vec![]
+    }
+
+    fn single() -> Self {
+        vec![vec![]]
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.extend(other.iter().cloned());
+    }
+
+    fn combine(&self, other: &Self) -> Self {
+        let mut combined = vec![];
+
+        for left in self {
+            for right in other {
+                combined.push(left.iter().chain(right.iter()).copied().collect());
+            }
+        }
+
+        combined
+    }
+
+    fn assign(&mut self, vertex: usize, pattern_vertex: usize) {
+        for embedding in self {
+            embedding.push((vertex, pattern_vertex));
+        }
+    }
+}
+
+/// The bag vertices mapped to pattern vertices and the pattern vertices used by forgotten vertices.
+type EmbeddingState = (BTreeMap<usize, usize>, u64);
+type EmbeddingTable<T> = HashMap<EmbeddingState, T>;
+
+fn solve_window<T: Embeddings>(pattern: &PatternGraph, window: &HashMapGraph) -> T {
+    let mut result = T::empty();
+
+    if window.order() < pattern.vertices.len() {
+        return result;
+    }
+
+    let (graph, mapping) = remap_vertices(window);
+    let td = min_degree_decomposition(&graph);
+    let nice_td = NiceTreeDecomposition::new(td);
+    let root = nice_td.td.root.unwrap();
+    let table: EmbeddingTable<T> = solve_rec(pattern, &graph, &mapping, &nice_td, root, usize::MAX);
+
+    for ((assigned, used), mut value) in table {
+        let mut mapped = used;
+
+        for (v, p) in &assigned {
+            mapped |= 1 << p;
+            value.assign(mapping[v], *p);
+        }
+
+        if mapped == pattern.full() {
+            result.merge(&value);
+        }
+    }
+
+    result
+}
+
+fn solve_rec<T: Embeddings>(
+    pattern: &PatternGraph,
+    graph: &HashMapGraph,
+    mapping: &HashMap<usize, usize>,
+    nice_td: &NiceTreeDecomposition,
+    id: usize,
+    parent_id: usize,
+) -> EmbeddingTable<T> {
+    let children: Vec<EmbeddingTable<T>> = get_children(&nice_td.td, id, parent_id)
+        .iter()
+        .map(|child_id| solve_rec(pattern, graph, mapping, nice_td, *child_id, id))
+        .collect();
+    let mut table = EmbeddingTable::new();
+
+    match nice_td.mapping[id] {
+        NiceTdNodeType::Leaf => {
+            let vertex = *nice_td.td.bags()[id].vertex_set.iter().next().unwrap();
+            table.insert((BTreeMap::new(), 0), T::single());
+
+            for p in 0..pattern.vertices.len() {
+                table.insert((BTreeMap::from([(vertex, p)]), 0), T::single());
+            }
+        }
+        NiceTdNodeType::Introduce(vertex) => {
+            for ((assigned, used), value) in &children[0] {
+                insert(&mut table, (assigned.clone(), *used), value);
+
+                let mapped = assigned.values().fold(*used, |mapped, p| mapped | 1 << p);
+
+                for p in 0..pattern.vertices.len() {
+                    if mapped & (1 << p) != 0 || used & pattern.neighbors[p] != 0 {
+                        continue;
+                    }
+
+                    if assigned.iter().any(|(u, q)| {
+                        pattern.neighbors[p] & (1 << q) != 0 && !graph.has_edge(*u, vertex)
+                    }) {
+                        continue;
+                    }
+
+                    let mut assigned = assigned.clone();
+                    assigned.insert(vertex, p);
+                    insert(&mut table, (assigned, *used), value);
+                }
+            }
+        }
+        NiceTdNodeType::Forget(vertex) => {
+            for ((assigned, used), value) in &children[0] {
+                let mut assigned = assigned.clone();
+
+                match assigned.remove(&vertex) {
+                    None => insert(&mut table, (assigned, *used), value),
+                    Some(p) => {
+                        let mapped = assigned.values().fold(*used, |mapped, q| mapped | 1 << q);
+
+                        // every neighbor has to be mapped before the vertex is forgotten
+                        if pattern.neighbors[p] & !mapped != 0 {
+                            continue;
+                        }
+
+                        let mut value = value.clone();
+                        value.assign(mapping[&vertex], p);
+                        insert(&mut table, (assigned, used | 1 << p), &value);
+                    }
+                }
+            }
+        }
+        NiceTdNodeType::Join => {
+            for ((assigned, left_used), left_value) in &children[0] {
+                for ((right_assigned, right_used), right_value) in &children[1] {
+                    if assigned != right_assigned || left_used & right_used != 0 {
+                        continue;
+                    }
+
+                    let value = left_value.combine(right_value);
+                    insert(
+                        &mut table,
+                        (assigned.clone(), left_used | right_used),
+                        &value,
+                    );
+                }
+            }
+        }
+    }
+
+    table
+}
+
+fn insert<T: Embeddings>(table: &mut EmbeddingTable<T>, state: EmbeddingState, value: &T) {
+    match table.get_mut(&state) {
+        Some(existing) => existing.merge(value),
+        None => {
+            table.insert(state, value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        contains_pattern, count_embeddings, count_pattern, list_pattern, PatternError, PatternGraph,
+    };
+    use crate::{generation::planar::generate, utils::convert::UndirectedGraph};
+    use itertools::Itertools;
+    use petgraph::stable_graph::NodeIndex;
+
+    fn brute_force_count_embeddings(pattern: &UndirectedGraph, host: &UndirectedGraph) -> usize {
+        let pattern_vertices: Vec<NodeIndex> = pattern.node_indices().collect();
+
+        host.node_indices()
+            .permutations(pattern_vertices.len())
+            .filter(|image| {
+                pattern.edge_indices().all(|e| {
+                    let (u, v) = pattern.edge_endpoints(e).unwrap();
+                    let u = image[pattern_vertices.iter().position(|w| *w == u).unwrap()];
+                    let v = image[pattern_vertices.iter().position(|w| *w == v).unwrap()];
+                    host.contains_edge(u, v)
+                })
+            })
+            .count()
+    }
+
+    fn pattern_graph(pattern: &UndirectedGraph) -> PatternGraph {
+        PatternGraph::new(pattern).unwrap()
+    }
+
+    fn path(n: u32) -> UndirectedGraph {
+        UndirectedGraph::from_edges((1..n).map(|i| (i - 1, i)))
+    }
+
+    fn cycle(n: u32) -> UndirectedGraph {
+        UndirectedGraph::from_edges((0..n).map(|i| (i, (i + 1) % n)))
+    }
+
+    #[test]
+    fn single_vertex() {
+        let host = generate(10, Some(1)).to_pet_graph();
+        let mut pattern = UndirectedGraph::default();
+        pattern.add_node(());
+
+        assert!(count_pattern(&pattern, &host).unwrap() == 10);
+    }
+
+    #[test]
+    fn triangles_in_cycle() {
+        assert!(!contains_pattern(&cycle(3), &cycle(6)).unwrap());
+        assert!(count_pattern(&cycle(3), &cycle(3)).unwrap() == 1);
+        assert!(count_pattern(&path(3), &cycle(6)).unwrap() == 6);
+    }
+
+    #[test]
+    fn automorphisms() {
+        assert!(count_embeddings(&pattern_graph(&cycle(4)), &cycle(4)) == 8);
+        assert!(count_embeddings(&pattern_graph(&path(4)), &path(4)) == 2);
+    }
+
+    #[test]
+    fn random_brute_force() {
+        let patterns = [path(2), path(3), path(4), cycle(3), cycle(4)];
+
+        for n in 4..9 {
+            let host = generate(n, Some(n as u64)).to_pet_graph();
+
+            for pattern in &patterns {
+                assert!(
+                    count_embeddings(&pattern_graph(pattern), &host)
+                        == brute_force_count_embeddings(pattern, &host)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn list_matches_count() {
+        let host = generate(30, Some(3)).to_pet_graph();
+
+        for pattern in [path(3), cycle(3), cycle(4)] {
+            let mappings = list_pattern(&pattern, &host).unwrap();
+            assert!(mappings.len() == count_pattern(&pattern, &host).unwrap());
+
+            for mapping in mappings {
+                for e in pattern.edge_indices() {
+                    let (u, v) = pattern.edge_endpoints(e).unwrap();
+                    assert!(host.contains_edge(
+                        NodeIndex::new(mapping[&u.index()]),
+                        NodeIndex::new(mapping[&v.index()])
+                    ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn disconnected_host() {
+        let mut host = cycle(3);
+        let u = host.add_node(());
+        let v = host.add_node(());
+        let w = host.add_node(());
+        host.add_edge(u, v, ());
+        host.add_edge(v, w, ());
+        host.add_edge(w, u, ());
+
+        assert!(count_pattern(&cycle(3), &host).unwrap() == 2);
+    }
+
+    #[test]
+    fn invalid_patterns() {
+        let host = cycle(4);
+        let mut looped = path(2);
+        looped.add_edge(0.into(), 0.into(), ());
+        let mut disconnected = path(2);
+        disconnected.add_node(());
+
+        assert_eq!(
+            count_pattern(&UndirectedGraph::default(), &host),
+            Err(PatternError::Empty)
+        );
+        assert_eq!(count_pattern(&path(65), &host), Err(PatternError::TooLarge));
+        assert_eq!(
+            contains_pattern(&disconnected, &host),
+            Err(PatternError::Disconnected)
+        );
+        assert_eq!(list_pattern(&looped, &host), Err(PatternError::Loop));
+    }
+}
@@ -1,77 +1,141 @@
-//! Contains the tree_decomposition function
+//! Contains functions computing tree decompositions of embedded graphs
 use arboretum_td::tree_decomposition::TreeDecomposition;
 use fxhash::FxHashSet;
 
 use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
 
+use crate::algorithm::dualgraph::dual_graph;
+use crate::algorithm::separator::{components, rotation_system, separate};
 use crate::algorithm::spantree::Span;
-use crate::data_structure::{
-    graph_dcel::GraphDCEL,
-    link_graph::{LinkDart, LinkFace, LinkGraphIter, LinkVertex},
-};
-
-fn tree_decomposition(
-    graph: &impl GraphDCEL<
-        LinkVertex,
-        LinkDart,
-        LinkFace,
-        LinkGraphIter<LinkVertex>,
-        LinkGraphIter<LinkDart>,
-        LinkGraphIter<LinkFace>,
-    >,
-    dual_graph: HashMap<LinkFace, HashSet<LinkFace>>,
-    spantree: &Span<LinkVertex>,
-    root_vertex: LinkFace,
+use crate::data_structure::graph_dcel::{Dart, Face, GraphDCEL, Vertex};
+
+/// Returns a tree decomposition of width `O(sqrt(n))` built by recursive planar separators.
+///
+/// The bags contain the ids of the vertices, so the decomposition can be passed to
+/// `dp_solve` for a graph using the same vertex ids.
+pub fn separator_tree_decomposition<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    graph: &impl GraphDCEL<V, D, F, VI, DI, FI>,
 ) -> TreeDecomposition {
+    let rotation = rotation_system(graph);
     let mut tree: TreeDecomposition = Default::default();
 
-    add_bags(root_vertex, 0, &mut tree, spantree, &dual_graph, graph);
+    add_separator_bags(
+        &rotation,
+        graph.get_vertexes().collect(),
+        HashSet::new(),
+        None,
+        &mut tree,
+    );
 
     tree
 }
 
-fn add_bags(
-    vertex: LinkFace,
-    parent: usize,
+/// Returns the face tree decomposition of the embedded graph.
+///
+/// Every face is a bag containing the vertices of the face and their paths to the root of a
+/// span tree. Two bags are adjacent if the faces share an edge not contained in the span tree.
+/// The bags contain the ids of the vertices. The graph is expected to be connected.
+pub fn face_tree_decomposition<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    graph: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+) -> TreeDecomposition {
+    let root = graph.get_vertexes().next().unwrap();
+
+    if graph.vertex_count() == 1 {
+        let mut tree: TreeDecomposition = Default::default();
+        tree.add_bag([root.get_id()].into_iter().collect());
+        return tree;
+    }
+
+    let spantree = Span::compute(graph, root);
+    let root_face = graph.face(&graph.get_darts().next().unwrap());
+
+    tree_decomposition(graph, dual_graph(graph, &spantree), &spantree, root_face)
+}
+
+/// Adds the bags for the vertices of `vertices` below the given parent bag.
+/// The boundary contains the vertices of the parent bag adjacent to `vertices`.
+fn add_separator_bags<V: Vertex + Eq + Hash + Clone>(
+    rotation: &HashMap<V, Vec<V>>,
+    vertices: HashSet<V>,
+    boundary: HashSet<V>,
+    parent: Option<usize>,
     tree: &mut TreeDecomposition,
-    spantree: &Span<LinkVertex>,
-    dual_graph: &HashMap<LinkFace, HashSet<LinkFace>>,
-    graph: &impl GraphDCEL<
-        LinkVertex,
-        LinkDart,
-        LinkFace,
-        LinkGraphIter<LinkVertex>,
-        LinkGraphIter<LinkDart>,
-        LinkGraphIter<LinkFace>,
-    >,
 ) {
-    let face_dart = graph.dart_face(&vertex);
+    let separator = if vertices.len() <= 4 {
+        vertices.clone()
+    } else {
+        separate(rotation, &vertices).separator
+    };
+    let bag: HashSet<V> = separator.union(&boundary).cloned().collect();
+    let id = tree.add_bag(bag.iter().map(|v| v.get_id()).collect());
 
-    let face_vertices = get_face_vertices(graph, face_dart);
+    if let Some(parent) = parent {
+        tree.add_edge(parent, id);
+    }
 
-    let bag = create_bag(face_vertices, &spantree);
+    for component in components(rotation, &vertices, &separator) {
+        let boundary = bag
+            .iter()
+            .filter(|v| rotation[*v].iter().any(|u| component.contains(u)))
+            .cloned()
+            .collect();
 
-    if parent == 0 {
-        tree.add_bag(bag);
-    } else {
-        tree.add_child_bags(parent, vec![bag]);
+        add_separator_bags(rotation, component, boundary, Some(id), tree);
     }
+}
 
-    for c in dual_graph.get(&vertex).unwrap_or(&HashSet::new()) {
-        add_bags(
-            c.clone(),
-            vertex.get_id(),
-            tree,
-            spantree,
-            dual_graph,
-            graph,
-        );
+// Returns the tree decomposition with a bag for every face of the face tree `dual_graph`
+// rooted at `root_vertex`
+fn tree_decomposition<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    graph: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+    dual_graph: HashMap<F, HashSet<F>>,
+    spantree: &Span<V>,
+    root_vertex: F,
+) -> TreeDecomposition {
+    let mut tree: TreeDecomposition = Default::default();
+    let mut stack = vec![(root_vertex, None)];
+
+    while let Some((vertex, parent)) = stack.pop() {
+        let face_vertices = get_face_vertices(graph, graph.dart_face(&vertex));
+        let id = tree.add_bag(create_bag(face_vertices, &spantree));
+
+        if let Some(parent) = parent {
+            tree.add_edge(parent, id);
+        }
+
+        for c in dual_graph.get(&vertex).into_iter().flatten() {
+            stack.push((c.clone(), Some(id)));
+        }
     }
+
+    tree
 }
 
-fn create_bag(
-    face_vertices: HashSet<LinkVertex>,
-    spantree: &&Span<LinkVertex>,
+fn create_bag<V: Vertex + Eq + Hash + Clone>(
+    face_vertices: HashSet<V>,
+    spantree: &&Span<V>,
 ) -> FxHashSet<usize> {
     let mut vertices: FxHashSet<usize> = FxHashSet::default();
 
@@ -88,34 +152,36 @@ fn create_bag(
     vertices
 }
 
-fn get_face_vertices(
-    graph: &impl GraphDCEL<
-        LinkVertex,
-        LinkDart,
-        LinkFace,
-        LinkGraphIter<LinkVertex>,
-        LinkGraphIter<LinkDart>,
-        LinkGraphIter<LinkFace>,
-    >,
-    mut dart: LinkDart,
-) -> HashSet<LinkVertex> {
-    let mut result: HashSet<LinkVertex> = HashSet::new();
-
-    while result.insert(graph.dart_target(&dart)) {
-        dart = graph.next(&dart);
-    }
-    result
+fn get_face_vertices<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    graph: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+    dart: D,
+) -> HashSet<V> {
+    graph
+        .dart_cycle(dart)
+        .map(|dart| graph.dart_target(&dart))
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::algorithm::dualgraph::dual_graph;
+    use super::{face_tree_decomposition, get_face_vertices, separator_tree_decomposition};
+    use crate::algorithm::dynamic_programming::solve::{
+        dp_solve_hashmap_graph, dp_solve_hashmap_graph_with_strategy, DpProblem, TdStrategy,
+    };
     use crate::algorithm::spantree::Span;
-    use crate::algorithm::tree_decomposition::tree_decomposition;
+    use crate::algorithm::tree_decomposition::{dual_graph, tree_decomposition};
     use crate::data_structure::graph_dcel::GraphDCEL;
     use crate::data_structure::link_graph::LinkGraph;
     use crate::embedding::{index::Embedding, maximal_planar::index::MaximalPlanar};
-    use crate::utils::convert::UndirectedGraph;
+    use crate::generation::{geometric::embed, planar::generate};
+    use crate::utils::convert::{dcel_to_hash_map_graph, UndirectedGraph};
     use fxhash::FxHashSet;
     use petgraph::stable_graph::StableGraph;
 
@@ -174,4 +240,67 @@ mod tests {
         cb.insert(lv2.get_id());
         assert_eq!(td.bags[0].vertex_set, cb)
     }
+
+    #[test]
+    fn face_tree_decomposition_valid() {
+        for n in (4..50).chain([200]) {
+            let lg = MaximalPlanar::embed(generate(n, Some(n as u64)).to_pet_graph());
+            let td = face_tree_decomposition(&lg);
+
+            assert_eq!(td.bags().len(), lg.edge_count() + 2 - lg.vertex_count());
+            assert!(td.verify(&dcel_to_hash_map_graph(&lg)).is_ok());
+        }
+    }
+
+    #[test]
+    fn face_tree_decomposition_dp() {
+        for n in 4..16 {
+            let lg = MaximalPlanar::embed(generate(n, Some(n as u64)).to_pet_graph());
+            let graph = dcel_to_hash_map_graph(&lg);
+            let prob = DpProblem::min_vertex_cover();
+
+            assert_eq!(
+                dp_solve_hashmap_graph_with_strategy(&graph, &TdStrategy::FaceTree(&lg), &prob)
+                    .len(),
+                dp_solve_hashmap_graph(&graph, None, &prob).len()
+            );
+        }
+    }
+
+    #[test]
+    fn separator_tree_decomposition_valid() {
+        for n in (4..50).chain([200, 800]) {
+            let lg = MaximalPlanar::embed(generate(n, Some(n as u64)).to_pet_graph());
+            let td = separator_tree_decomposition(&lg);
+            let width = td.bags().iter().map(|b| b.vertex_set.len()).max().unwrap() - 1;
+
+            assert!(td.verify(&dcel_to_hash_map_graph(&lg)).is_ok());
+            assert!(width as f64 <= 10.0 * (n as f64).sqrt());
+        }
+    }
+
+    #[test]
+    fn separator_tree_decomposition_dp() {
+        for n in 4..16 {
+            let lg = MaximalPlanar::embed(generate(n, Some(n as u64)).to_pet_graph());
+            let graph = dcel_to_hash_map_graph(&lg);
+            let td = separator_tree_decomposition(&lg);
+            let prob = DpProblem::max_independent_set();
+
+            assert_eq!(
+                dp_solve_hashmap_graph(&graph, Some(td), &prob).len(),
+                dp_solve_hashmap_graph(&graph, None, &prob).len()
+            );
+        }
+    }
+
+    #[test]
+    fn face_vertices_with_repeated_vertex() {
+        // the only face of a path visits the middle vertex twice
+        let lg = embed(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)], &[(0, 1), (1, 2)]).to_link_graph();
+
+        for dart in lg.get_darts() {
+            assert_eq!(get_face_vertices(&lg, dart).len(), 3);
+        }
+    }
 }
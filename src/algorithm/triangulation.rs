@@ -1,23 +1,21 @@
 //! Contains the triangulate function
-use crate::data_structure::{
-    graph_dcel::GraphDCEL,
-    link_graph::{LinkDart, LinkFace, LinkGraphIter, LinkVertex},
-};
+use crate::data_structure::graph_dcel::{Dart, Face, GraphDCEL, Vertex};
 use std::collections::HashSet;
+use std::hash::Hash;
 
 /// Returns the edges of a graph that need to be added to be fully triangulated.
 /// The graph needs to be connected.
-pub fn triangulate(
-    graph: &impl GraphDCEL<
-        LinkVertex,
-        LinkDart,
-        LinkFace,
-        LinkGraphIter<LinkVertex>,
-        LinkGraphIter<LinkDart>,
-        LinkGraphIter<LinkFace>,
-    >,
-) -> HashSet<(LinkVertex, LinkVertex)> {
-    let mut edges: HashSet<(LinkVertex, LinkVertex)> = HashSet::new();
+pub fn triangulate<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    graph: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+) -> HashSet<(V, V)> {
+    let mut edges: HashSet<(V, V)> = HashSet::new();
 
     for face in graph.get_faces() {
         edges.extend(triangulate_face(graph, &face));
@@ -27,45 +25,39 @@ pub fn triangulate(
 }
 
 /// Returns the edges of a face that need to be added to be fully triangulated.
-fn triangulate_face(
-    graph: &impl GraphDCEL<
-        LinkVertex,
-        LinkDart,
-        LinkFace,
-        LinkGraphIter<LinkVertex>,
-        LinkGraphIter<LinkDart>,
-        LinkGraphIter<LinkFace>,
-    >,
-    face: &LinkFace,
-) -> HashSet<(LinkVertex, LinkVertex)> {
-    let mut edges: HashSet<(LinkVertex, LinkVertex)> = HashSet::new();
-
+fn triangulate_face<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    graph: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+    face: &F,
+) -> HashSet<(V, V)> {
     let mut current = graph.dart_face(face);
 
     if graph.next(&graph.next(&current)) == current {
         // single edge (|v| < 3)
-        return edges;
+        return HashSet::new();
     } else if graph.dart_target(&graph.next(&current)) == graph.dart_target(&graph.twin(&current)) {
         // outgoing edge
         current = graph.next(&current);
     }
 
-    let start_vertex = &graph.dart_target(&graph.twin(&current));
-
-    loop {
-        let next = graph.next(&current);
-
-        if graph.dart_target(&graph.next(&next)) == *start_vertex {
-            break;
-        }
-
-        let from = graph.dart_target(&next);
-
-        edges.insert((from, start_vertex.clone()));
-        current = next;
-    }
-
-    edges
+    let start_vertex = graph.dart_target(&graph.twin(&current));
+    let targets: Vec<V> = graph
+        .dart_cycle(current)
+        .map(|dart| graph.dart_target(&dart))
+        .collect();
+
+    targets
+        .windows(2)
+        .skip(1)
+        .take_while(|pair| pair[1] != start_vertex)
+        .map(|pair| (pair[0].clone(), start_vertex.clone()))
+        .collect()
 }
 
 #[cfg(test)]
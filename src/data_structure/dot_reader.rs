@@ -1,74 +1,218 @@
 //! Creates a Pedgraph instance from the Dot Format
 //! ```
 //! use std::fs::File;
-//! use graph_algo_ptas::data_structure::dot_reader::read_graph;
+//! use graph_algo_ptas::data_structure::dot_reader::{read_graph, read_weighted_graph};
 //!
 //! let input = "graph g { 0 -- 1 }"; // the input in dot format
-//! let g = read_graph(input.to_string()); // create Pedgraph instance
+//! let g = read_graph(input.to_string()).unwrap(); // create Pedgraph instance
+//!
+//! // node names are kept as labels together with the weights
+//! let input = "graph g { a [weight=2]\n a -- b -- c [weight=0.5]\n {a b} -- d }";
+//! let g = read_weighted_graph(input).unwrap();
+//! assert_eq!(g.node_count(), 4);
+//! assert_eq!(g.edge_count(), 4);
 //! ```
 
-use graphviz_parser::ast_nodes::Statement::{Edge, Node};
-use graphviz_parser::ast_nodes::{EdgeLHS, EdgeRHS};
+use graphviz_parser::ast_nodes::{
+    Assignment, Edge, EdgeLHS, EdgeRHS, Statement, Statement::Node, Subgraph,
+};
 use graphviz_parser::DotGraph;
-use std::{collections::HashMap, str::FromStr};
+use petgraph::stable_graph::{NodeIndex, StableGraph};
+use petgraph::Undirected;
+use std::{collections::HashMap, fmt, str::FromStr};
 
 use crate::utils::convert::UndirectedGraph;
 
+/// The node of a graph read from the Dot Format
+#[derive(Debug, Clone, PartialEq)]
+pub struct DotNode {
+    /// The name of the node in the input
+    pub label: String,
+    /// The value of the `weight` attribute of the node
+    pub weight: Option<f64>,
+}
+
+/// A graph read from the Dot Format, the edges are weighted by their `weight` attribute
+pub type WeightedGraph = StableGraph<DotNode, Option<f64>, Undirected>;
+
+/// The errors occurring when reading a graph in the Dot Format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DotError {
+    /// The input is not a valid Dot graph
+    Parse(String),
+    /// The input is a directed graph
+    Directed,
+    /// The `weight` attribute with the given value is not a number
+    InvalidWeight(String),
+}
+
+impl fmt::Display for DotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DotError::Parse(message) => write!(f, "invalid dot graph: {}", message),
+            DotError::Directed => write!(f, "directed graphs are not supported"),
+            DotError::InvalidWeight(weight) => write!(f, "invalid weight {}", weight),
+        }
+    }
+}
+
+impl std::error::Error for DotError {}
+
 /// Generates an Pedgraph from a Dot Format string
-pub fn read_graph(graph_text: String) -> Option<UndirectedGraph> {
-    let graph_ast = DotGraph::from_str(&graph_text);
-
-    match graph_ast {
-        Ok(DotGraph::Undirected(g)) => {
-            let mut graph: UndirectedGraph = Default::default();
-            let mut node_mapper = HashMap::new();
-
-            g.statements.iter().for_each(|s| {
-                if let Node(n) = s {
-                    let idx = graph.add_node(());
-                    node_mapper.insert(n.id.clone(), idx);
-                }
+pub fn read_graph(graph_text: String) -> Result<UndirectedGraph, DotError> {
+    read_weighted_graph(&graph_text).map(|graph| graph.map(|_, _| (), |_, _| ()))
+}
 
-                if let Edge(e) = s {
-                    if let EdgeLHS::Node(n1) = &e.lhs {
-                        if let EdgeRHS::Node(n2) = e.rhs.as_ref() {
-                            let a = get_or_create_node(n1.id.clone(), &mut graph, &mut node_mapper);
-                            let b = get_or_create_node(n2.id.clone(), &mut graph, &mut node_mapper);
-                            graph.add_edge(a, b, ());
-                        }
+/// Generates a graph from a Dot Format string, which keeps the names of the nodes and the
+/// weights of nodes and edges.
+///
+/// Edge chains like `a -- b -- c` add an edge between every pair of consecutive nodes and
+/// subgraphs like `{a b} -- c` connect every node of the subgraph. An edge occurring several
+/// times in the input is added as parallel edges.
+pub fn read_weighted_graph(graph_text: &str) -> Result<WeightedGraph, DotError> {
+    match parse(graph_text)? {
+        DotGraph::Undirected(g) => {
+            let mut reader = Reader::default();
+            reader.statements(&g.statements)?;
+            Ok(reader.graph)
+        }
+        DotGraph::Directed(_) => Err(DotError::Directed),
+    }
+}
+
+// Parses the input, graphs without a name are rejected beforehand as the parser panics on them
+fn parse(graph_text: &str) -> Result<DotGraph, DotError> {
+    let mut header = graph_text
+        .split('{')
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .skip_while(|word| word.eq_ignore_ascii_case("strict"))
+        .skip(1);
+    if header.next().is_none() {
+        return Err(DotError::Parse("the graph requires a name".to_string()));
+    }
+
+    DotGraph::from_str(graph_text).map_err(|error| DotError::Parse(error.to_string()))
+}
+
+#[derive(Default)]
+struct Reader {
+    graph: WeightedGraph,
+    node_mapper: HashMap<String, NodeIndex>,
+    // every node in the order of its occurrences, used to find the nodes of a subgraph
+    occurrences: Vec<NodeIndex>,
+}
+
+impl Reader {
+    fn statements(&mut self, statements: &[Statement]) -> Result<(), DotError> {
+        for statement in statements {
+            match statement {
+                Node(n) => {
+                    let idx = self.node(&n.id);
+                    if let Some(weight) = weight(&n.attribute_list)? {
+                        self.graph[idx].weight = Some(weight);
                     }
                 }
-            });
-
-            Some(graph)
+                Statement::Edge(e) => self.edge(e)?,
+                Statement::Subgraph(s) => {
+                    self.subgraph(s)?;
+                }
+                _ => {}
+            }
         }
-        _ => {
-            eprintln!("Invalid input graph");
-            None
+
+        Ok(())
+    }
+
+    // Adds the edges between all consecutive endpoints of the edge chain
+    fn edge(&mut self, edge: &Edge) -> Result<(), DotError> {
+        let weight = weight(&edge.attribute_list)?;
+        let mut from = match &edge.lhs {
+            EdgeLHS::Node(n) => vec![self.node(&n.id)],
+            EdgeLHS::Subgraph(s) => self.subgraph(s)?,
+        };
+        let mut rhs = edge.rhs.as_ref();
+
+        loop {
+            let (to, next) = match rhs {
+                EdgeRHS::Node(n) => (vec![self.node(&n.id)], None),
+                EdgeRHS::Subgraph(s) => (self.subgraph(s)?, None),
+                EdgeRHS::Edge(e) => (
+                    match &e.lhs {
+                        EdgeLHS::Node(n) => vec![self.node(&n.id)],
+                        EdgeLHS::Subgraph(s) => self.subgraph(s)?,
+                    },
+                    Some(e.rhs.as_ref()),
+                ),
+            };
+
+            for &a in &from {
+                for &b in &to {
+                    self.graph.add_edge(a, b, weight);
+                }
+            }
+
+            match next {
+                Some(next) => {
+                    from = to;
+                    rhs = next;
+                }
+                None => return Ok(()),
+            }
         }
     }
+
+    // Adds the statements of the subgraph and returns the nodes occurring in it
+    fn subgraph(&mut self, subgraph: &Subgraph) -> Result<Vec<NodeIndex>, DotError> {
+        let first = self.occurrences.len();
+        self.statements(&subgraph.statements)?;
+
+        let mut nodes = self.occurrences[first..].to_vec();
+        nodes.sort_unstable();
+        nodes.dedup();
+        Ok(nodes)
+    }
+
+    fn node(&mut self, id: &str) -> NodeIndex {
+        let graph = &mut self.graph;
+        let idx = *self.node_mapper.entry(id.to_string()).or_insert_with(|| {
+            graph.add_node(DotNode {
+                label: id.to_string(),
+                weight: None,
+            })
+        });
+        self.occurrences.push(idx);
+        idx
+    }
 }
 
-fn get_or_create_node(
-    node: String,
-    graph: &mut UndirectedGraph,
-    node_mapper: &mut HashMap<String, petgraph::stable_graph::NodeIndex>,
-) -> petgraph::stable_graph::NodeIndex {
-    *node_mapper
-        .entry(node)
-        .or_insert_with(|| graph.add_node(()))
+// Returns the value of the `weight` attribute
+fn weight(attributes: &Option<Vec<Assignment>>) -> Result<Option<f64>, DotError> {
+    attributes
+        .iter()
+        .flatten()
+        .find(|assignment| assignment.lhs == "weight")
+        .map(|assignment| {
+            assignment
+                .rhs
+                .trim_matches('"')
+                .parse()
+                .map_err(|_| DotError::InvalidWeight(assignment.rhs.clone()))
+        })
+        .transpose()
 }
 
 #[cfg(test)]
 mod tests {
-    use super::read_graph;
+    use super::{read_graph, read_weighted_graph, DotError};
 
     #[test]
     fn small_graph() {
         let input = "graph g { 0[]\n 1[]\n 2[]\n 3[]\n 0 -- 1\n 2 --3\n 2 -- 1 }";
         let g = read_graph(input.to_string());
 
-        assert!(g.is_some());
+        assert!(g.is_ok());
         let graph = g.unwrap();
         assert_eq!(graph.node_count(), 4);
         assert_eq!(graph.edge_count(), 3);
@@ -79,24 +223,71 @@ mod tests {
         let input = "graph g { 0 -- 1\n 2 --3\n 2 -- 1\n 10 -- 20\n 4 -- 1\n 5 -- 3 }";
         let g = read_graph(input.to_string());
 
-        assert!(g.is_some());
+        assert!(g.is_ok());
         let graph = g.unwrap();
         assert_eq!(graph.node_count(), 8);
         assert_eq!(graph.edge_count(), 6);
     }
 
+    #[test]
+    fn chains_and_subgraphs() {
+        let input = "graph g { 0 -- 1 -- 2 -- 3\n {4 5} -- 6\n subgraph s { 7 -- 8 } }";
+        let graph = read_graph(input.to_string()).unwrap();
+
+        assert_eq!(graph.node_count(), 9);
+        assert_eq!(graph.edge_count(), 6);
+    }
+
+    #[test]
+    fn weights() {
+        let input = "graph g { a [weight=2]\n a -- b [weight=1.5]\n b -- c }";
+        let graph = read_weighted_graph(input).unwrap();
+        let a = graph
+            .node_indices()
+            .find(|&v| graph[v].label == "a")
+            .unwrap();
+        let b = graph
+            .node_indices()
+            .find(|&v| graph[v].label == "b")
+            .unwrap();
+
+        assert_eq!(graph[a].weight, Some(2.0));
+        assert_eq!(graph[b].weight, None);
+        assert_eq!(graph[graph.find_edge(a, b).unwrap()], Some(1.5));
+        assert_eq!(
+            read_weighted_graph("graph g { a [weight=x] }").unwrap_err(),
+            DotError::InvalidWeight("x".to_string())
+        );
+    }
+
     #[test]
     fn digraph() {
-        let input = "digraph g { 0 -- 1 }";
+        let input = "digraph g { 0 -> 1 }";
         let g = read_graph(input.to_string());
 
-        assert!(g.is_none());
+        assert_eq!(g.unwrap_err(), DotError::Directed);
     }
 
     #[test]
-    #[should_panic]
     fn invalid_graph() {
         let input = "graph { 0 -- 1 }";
-        read_graph(input.to_string());
+
+        assert_eq!(
+            read_graph(input.to_string()).unwrap_err(),
+            DotError::Parse("the graph requires a name".to_string())
+        );
+        assert_eq!(
+            read_graph("strict graph{ 0 -- 1 }".to_string()).unwrap_err(),
+            DotError::Parse("the graph requires a name".to_string())
+        );
+    }
+
+    #[test]
+    fn parallel_edges() {
+        let input = "graph g { 0 -- 1\n 1 -- 0\n {0 1} -- 2\n 0 -- 2 }";
+        let graph = read_graph(input.to_string()).unwrap();
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 5);
     }
 }
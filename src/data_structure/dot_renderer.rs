@@ -1,4 +1,5 @@
-//! Implements the dot GraphWalker trait for LinkGraph to allow dot creation
+//! Implements the dot GraphWalker trait for LinkGraph to allow dot creation.
+//! The weights of the vertexes and darts are used as labels.
 //! ```
 //! use std::fs::File;
 //! use graph_algo_ptas::data_structure::link_graph::LinkGraph;
@@ -10,9 +11,10 @@
 
 use crate::data_structure::graph_dcel::GraphDCEL;
 use crate::data_structure::link_graph::{LinkDart, LinkGraph, LinkVertex};
-use dot::{Edges, GraphWalk, Id, Labeller, Nodes};
+use dot::{Edges, GraphWalk, Id, LabelText, Labeller, Nodes};
+use std::fmt::Debug;
 
-impl<'a> GraphWalk<'a, LinkVertex, LinkDart> for LinkGraph {
+impl<'a, N, E, F> GraphWalk<'a, LinkVertex, LinkDart> for LinkGraph<N, E, F> {
     fn nodes(&'a self) -> Nodes<'a, LinkVertex> {
         self.get_vertexes().collect()
     }
@@ -30,7 +32,12 @@ impl<'a> GraphWalk<'a, LinkVertex, LinkDart> for LinkGraph {
     }
 }
 
-impl<'a> Labeller<'a, LinkVertex, LinkDart> for LinkGraph {
+// the dot crate does not escape quotes in labels, which debug output of strings contains
+fn weight_label<'a, T: Debug>(weight: &T) -> LabelText<'a> {
+    LabelText::label(format!("{:?}", weight).replace('"', "\\\""))
+}
+
+impl<'a, N: Debug, E: Debug, F> Labeller<'a, LinkVertex, LinkDart> for LinkGraph<N, E, F> {
     fn graph_id(&'a self) -> Id<'a> {
         Id::new("Test").unwrap()
     }
@@ -41,11 +48,25 @@ impl<'a> Labeller<'a, LinkVertex, LinkDart> for LinkGraph {
         let id = Id::new(name);
         id.unwrap()
     }
+
+    fn node_label(&'a self, vertex: &LinkVertex) -> LabelText<'a> {
+        match self.vertex_weight(vertex) {
+            Some(weight) => weight_label(weight),
+            None => LabelText::label(format!("N{}", vertex.get_id())),
+        }
+    }
+
+    fn edge_label(&'a self, dart: &LinkDart) -> LabelText<'a> {
+        match self.dart_weight(dart) {
+            Some(weight) => weight_label(weight),
+            None => LabelText::label(""),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::data_structure::link_graph::LinkGraph;
+    use crate::data_structure::{graph_dcel::GraphDCEL, link_graph::LinkGraph};
     use crate::utils::single_face::generate_single_face;
     use std::borrow::Borrow;
     use std::fs::File;
@@ -61,6 +82,24 @@ mod tests {
         dot::render(lg.borrow(), &mut f).unwrap()
     }
 
+    #[test]
+    fn weights() {
+        let mut lg: LinkGraph<&str, usize> = LinkGraph::default();
+        let v1 = lg.new_vertex();
+        let v2 = lg.new_vertex();
+        let (dart, _) = lg.new_edge(v1.clone(), v2, None, None, None, None);
+        lg.set_vertex_weight(&v1, "source");
+        lg.set_dart_weight(&dart, 42);
+
+        let mut out = vec![];
+        dot::render(&lg, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains(r#"label="\"source\"""#));
+        assert!(out.contains("label=\"42\""));
+        assert!(out.contains("label=\"N1\""));
+    }
+
     #[test]
     fn circle() {
         let mut f = File::create("circle.dot").unwrap();
@@ -1,13 +1,19 @@
 //! Contains the traits used to represent a doubly connected edge list
 
+use std::collections::HashSet;
+use std::hash::Hash;
+
 /// Trait to mark a dart type
 pub trait Dart {}
 
 /// Trait to mark a face
 pub trait Face {}
 
-/// Trait to mark a vertex
-pub trait Vertex {}
+/// Trait to be implemented by every vertex type
+pub trait Vertex {
+    /// Returns the id of the vertex
+    fn get_id(&self) -> usize;
+}
 
 /// Trait to be implemented by every vertex implementation
 pub trait GraphDCEL<
@@ -19,6 +25,13 @@ pub trait GraphDCEL<
     FI: Iterator<Item = F>,
 >
 {
+    /// The type of the weights of the vertexes
+    type VertexWeight;
+    /// The type of the weights of the darts
+    type DartWeight;
+    /// The type of the weights of the faces
+    type FaceWeight;
+
     /// Returns all vertex in the graph as an iterator
     fn get_vertexes(&self) -> VI;
     /// Returns all darts in the graph as an iterator
@@ -75,5 +88,199 @@ pub trait GraphDCEL<
     fn add_face(&mut self, dart: D) -> F;
 
     /// Sets the face for the given Dart
-    fn set_face(&self, dart: &D, face: F);
+    fn set_face(&mut self, dart: &D, face: F);
+    /// Replaces the face and the previous and next darts of the given dart
+    fn change_face(&mut self, dart: &D, face: Option<F>, prev: Option<D>, next: Option<D>);
+    /// Removes the given dart starting at `from` together with its twin.
+    /// Returns the removed dart and its twin.
+    fn remove_edge(&mut self, from: &V, dart: D) -> (D, D);
+
+    /// Splits the face of the darts `a` and `b` by a new edge from the target of `a` to the
+    /// target of `b`. Returns the new dart following `a` and its twin.
+    fn split_face(&mut self, a: &D, b: &D) -> (D, D);
+    /// Merges the two faces separated by the given dart by removing its edge.
+    /// Returns the merged face.
+    fn merge_faces(&mut self, dart: &D) -> F;
+    /// Subdivides the edge of the given dart by a new vertex, which is returned
+    fn subdivide_edge(&mut self, dart: &D) -> V;
+    /// Contracts the edge of the given dart into the source of the dart, which is returned.
+    /// Parallel edges created by the contraction are removed.
+    fn contract_edge(&mut self, dart: &D) -> V;
+    /// Removes the given vertex together with all incident edges
+    fn remove_vertex(&mut self, vertex: &V);
+
+    /// Returns the weight of the given vertex
+    fn vertex_weight(&self, vertex: &V) -> Option<&Self::VertexWeight>;
+    /// Sets the weight of the given vertex and returns the previous weight
+    fn set_vertex_weight(
+        &mut self,
+        vertex: &V,
+        weight: Self::VertexWeight,
+    ) -> Option<Self::VertexWeight>;
+    /// Returns the weight of the given dart
+    fn dart_weight(&self, dart: &D) -> Option<&Self::DartWeight>;
+    /// Sets the weight of the given dart and returns the previous weight
+    fn set_dart_weight(&mut self, dart: &D, weight: Self::DartWeight) -> Option<Self::DartWeight>;
+    /// Returns the weight of the given face
+    fn face_weight(&self, face: &F) -> Option<&Self::FaceWeight>;
+    /// Sets the weight of the given face and returns the previous weight
+    fn set_face_weight(&mut self, face: &F, weight: Self::FaceWeight) -> Option<Self::FaceWeight>;
+
+    /// Returns the darts of the face cycle starting at the given dart in `next` order
+    fn dart_cycle(&self, dart: D) -> impl Iterator<Item = D>
+    where
+        D: Clone + PartialEq,
+    {
+        let first = dart.clone();
+        let mut current = Some(dart);
+
+        std::iter::from_fn(move || {
+            let dart = current.take()?;
+            let next = self.next(&dart);
+
+            if next != first {
+                current = Some(next);
+            }
+
+            Some(dart)
+        })
+    }
+
+    /// Returns the darts of the given face in `next` order
+    fn face_darts(&self, face: &F) -> impl Iterator<Item = D>
+    where
+        D: Clone + PartialEq,
+    {
+        self.dart_cycle(self.dart_face(face))
+    }
+
+    /// Returns the vertexes of the given face in `next` order. A vertex is returned once for
+    /// every time the face boundary passes through it.
+    fn face_vertices(&self, face: &F) -> impl Iterator<Item = V>
+    where
+        D: Clone + PartialEq,
+    {
+        self.face_darts(face).map(|dart| self.dart_target(&dart))
+    }
+
+    /// Returns the darts starting at the given vertex in rotation order
+    fn vertex_rotation(&self, vertex: &V) -> impl Iterator<Item = D>
+    where
+        D: Clone + PartialEq,
+    {
+        let first = self.dart_vertex(vertex);
+        let mut current = Some(first.clone());
+
+        std::iter::from_fn(move || {
+            let dart = current.take()?;
+            let next = self.next(&self.twin(&dart));
+
+            if next != first {
+                current = Some(next);
+            }
+
+            Some(dart)
+        })
+    }
+
+    /// Returns the distinct faces incident to the given vertex in rotation order
+    fn incident_faces(&self, vertex: &V) -> impl Iterator<Item = F>
+    where
+        D: Clone + PartialEq,
+        F: Clone + PartialEq,
+    {
+        let mut seen = vec![];
+
+        self.vertex_rotation(vertex).filter_map(move |dart| {
+            let face = self.face(&dart);
+
+            if seen.contains(&face) {
+                None
+            } else {
+                seen.push(face.clone());
+                Some(face)
+            }
+        })
+    }
+}
+
+/// The changes required to remove a set of darts from a graph, see [dart_removal]
+pub(crate) struct DartRemoval<V, D, F> {
+    /// The vertexes whose dart is removed together with their new dart, `None` if the vertex
+    /// has no remaining dart
+    pub vertex_darts: Vec<(V, Option<D>)>,
+    /// The remaining darts `(prev, next)` which become consecutive in their face cycle
+    pub links: Vec<(D, D)>,
+    /// The distinct faces of the removed darts
+    pub faces: Vec<F>,
+}
+
+/// Returns the changes required to remove the given darts, which have to contain the twin of
+/// every dart. If `contract` is set, the removed edges are contracted instead of deleted.
+///
+/// The graph is not modified, so every implementation applies the changes to its own storage
+/// and reassigns the faces of the cycles starting at the `prev` darts of the links.
+pub(crate) fn dart_removal<
+    V: Vertex,
+    D: Dart + Clone + Eq + Hash,
+    F: Face + PartialEq,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    graph: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+    darts: &[D],
+    contract: bool,
+) -> DartRemoval<V, D, F> {
+    let removed = darts.iter().cloned().collect::<HashSet<_>>();
+    let mut removal = DartRemoval {
+        vertex_darts: vec![],
+        links: vec![],
+        faces: vec![],
+    };
+
+    for dart in darts {
+        let source = graph.dart_target(&graph.twin(dart));
+
+        if &graph.dart_vertex(&source) == dart {
+            let mut current = graph.next(&graph.twin(dart));
+
+            while removed.contains(&current) && &current != dart {
+                current = graph.next(&graph.twin(&current));
+            }
+
+            let current = Some(current).filter(|d| !removed.contains(d));
+            removal.vertex_darts.push((source, current));
+        }
+    }
+
+    // the next darts of removed darts are never changed, so the links can be found first
+    for dart in darts {
+        let face = graph.face(dart);
+
+        if !removal.faces.contains(&face) {
+            removal.faces.push(face);
+        }
+
+        let prev = graph.prev(dart);
+
+        if !removed.contains(&prev) {
+            let successor = |d: &D| {
+                if contract {
+                    graph.next(d)
+                } else {
+                    graph.next(&graph.twin(d))
+                }
+            };
+            let mut next = successor(dart);
+
+            while removed.contains(&next) {
+                next = successor(&next);
+            }
+
+            removal.links.push((prev, next));
+        }
+    }
+
+    removal
 }
@@ -0,0 +1,513 @@
+//! Contains readers and writers for the DIMACS, METIS and PACE graph formats and for PACE tree
+//! decompositions.
+//!
+//! The nodes are numbered from 1 in all formats and from 0 in the graphs and tree
+//! decompositions of the crate.
+//!
+//! ```rust
+//! use graph_algo_ptas::algorithm::dynamic_programming::solve::{dp_solve, DpProblem};
+//! use graph_algo_ptas::data_structure::io::{read_pace_gr, read_pace_td, write_dimacs};
+//!
+//! let graph = read_pace_gr("p tw 3 2\n1 2\n2 3\n").unwrap();
+//! let td = read_pace_td("s td 2 2 3\nb 1 1 2\nb 2 2 3\n1 2\n").unwrap();
+//! let sol = dp_solve(&graph, Some(td), &DpProblem::max_independent_set());
+//! assert_eq!(sol.len(), 2);
+//! assert_eq!(write_dimacs(&graph), "p edge 3 2\ne 1 2\ne 2 3\n");
+//! ```
+
+use crate::utils::convert::UndirectedGraph;
+use arboretum_td::tree_decomposition::TreeDecomposition;
+use fxhash::FxHashSet;
+use petgraph::stable_graph::NodeIndex;
+use std::{collections::HashMap, fmt, fmt::Write};
+
+/// The errors occurring when reading a graph or tree decomposition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatError {
+    /// The header line is missing or invalid
+    Header,
+    /// The line with the given number is invalid
+    Line(usize),
+    /// The number of nodes, edges or bags differs from the header
+    Count,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::Header => write!(f, "missing or invalid header"),
+            FormatError::Line(line) => write!(f, "invalid line {}", line),
+            FormatError::Count => write!(f, "the content does not match the header"),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+/// Reads a graph in the DIMACS format, which consists of a `p edge <nodes> <edges>` line and an
+/// `e <u> <v>` line for every edge. Lines starting with `c` are comments.
+pub fn read_dimacs(text: &str) -> Result<UndirectedGraph, FormatError> {
+    let mut lines = content_lines(text, "c");
+    let [n, m] = header(lines.next(), &["p", "edge"])?;
+
+    read_edges(lines, n, m, |words| match words {
+        ["e", u, v] => Some((*u, *v)),
+        _ => None,
+    })
+}
+
+/// Writes a graph in the DIMACS format, see [read_dimacs].
+pub fn write_dimacs(graph: &UndirectedGraph) -> String {
+    let mut text = format!("p edge {} {}\n", graph.node_count(), graph.edge_count());
+    write_edges(graph, &mut text, "e ");
+    text
+}
+
+/// Reads a graph in the PACE `.gr` format, which consists of a `p tw <nodes> <edges>` line and
+/// a `<u> <v>` line for every edge. Lines starting with `c` are comments.
+pub fn read_pace_gr(text: &str) -> Result<UndirectedGraph, FormatError> {
+    let mut lines = content_lines(text, "c");
+    let [n, m] = header(lines.next(), &["p", "tw"])?;
+
+    read_edges(lines, n, m, |words| match words {
+        [u, v] => Some((*u, *v)),
+        _ => None,
+    })
+}
+
+/// Writes a graph in the PACE `.gr` format, see [read_pace_gr].
+pub fn write_pace_gr(graph: &UndirectedGraph) -> String {
+    let mut text = format!("p tw {} {}\n", graph.node_count(), graph.edge_count());
+    write_edges(graph, &mut text, "");
+    text
+}
+
+/// Reads a graph in the METIS format, which consists of a `<nodes> <edges> [<fmt> [<ncon>]]`
+/// line and a line for every node listing its neighbors. Node sizes and weights as well as
+/// edge weights given by `fmt` are skipped. Lines starting with `%` are comments.
+pub fn read_metis(text: &str) -> Result<UndirectedGraph, FormatError> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.split_whitespace().collect::<Vec<_>>()))
+        .filter(|(_, words)| !matches!(words.first(), Some(word) if word.starts_with('%')))
+        .skip_while(|(_, words)| words.is_empty());
+
+    let words = lines.next().ok_or(FormatError::Header)?.1;
+    let numbers = words
+        .iter()
+        .enumerate()
+        .map(|(i, word)| match i {
+            // the format is a binary number whose leading zeros may be omitted
+            2 => format!("{:0>3}", word).parse::<usize>().ok(),
+            _ => word.parse().ok(),
+        })
+        .collect::<Option<Vec<_>>>()
+        .filter(|numbers| (2..=4).contains(&numbers.len()))
+        .ok_or(FormatError::Header)?;
+    let (n, m) = (numbers[0], numbers[1]);
+    let format = numbers.get(2).copied().unwrap_or(0);
+    let weights = if format / 10 % 10 == 1 {
+        numbers.get(3).copied().unwrap_or(1)
+    } else {
+        0
+    };
+    let skip = format / 100 + weights;
+    let step = 1 + format % 10;
+
+    let mut graph = empty_graph(n);
+    let mut edges = 0;
+
+    for v in 0..n {
+        let (line, words) = lines.next().ok_or(FormatError::Count)?;
+        if words.len() < skip || (words.len() - skip) % step != 0 {
+            return Err(FormatError::Line(line));
+        }
+
+        for word in words[skip..].iter().step_by(step) {
+            let u = node(word, n, line)?;
+
+            if u.index() > v {
+                graph.update_edge(NodeIndex::new(v), u, ());
+                edges += 1;
+            }
+        }
+    }
+
+    if edges != m || lines.any(|(_, words)| !words.is_empty()) {
+        return Err(FormatError::Count);
+    }
+
+    Ok(graph)
+}
+
+/// Writes a graph in the METIS format, see [read_metis].
+pub fn write_metis(graph: &UndirectedGraph) -> String {
+    let ids = ids(graph);
+    let mut text = format!("{} {}\n", graph.node_count(), graph.edge_count());
+
+    for v in graph.node_indices() {
+        let neighbors = graph
+            .neighbors(v)
+            .map(|u| ids[&u].to_string())
+            .collect::<Vec<_>>();
+        writeln!(text, "{}", neighbors.join(" ")).unwrap();
+    }
+
+    text
+}
+
+/// Reads a tree decomposition in the PACE `.td` format, which consists of a
+/// `s td <bags> <max bag size> <nodes>` line, a `b <bag> <nodes>..` line for every bag and a
+/// `<bag> <bag>` line for every edge of the tree. Lines starting with `c` are comments.
+/// The `b - 1` edges have to connect all bags.
+///
+/// The tree decomposition can be passed to [dp_solve](crate::algorithm::dynamic_programming::solve::dp_solve).
+pub fn read_pace_td(text: &str) -> Result<TreeDecomposition, FormatError> {
+    let mut lines = content_lines(text, "c");
+    let [b, w, n] = header(lines.next(), &["s", "td"])?;
+    let mut bags: Vec<Option<FxHashSet<usize>>> = vec![None; b];
+    let mut edges = vec![];
+    // the representative of the tree containing every bag
+    let mut roots: Vec<usize> = (0..b).collect();
+
+    for (line, words) in lines {
+        match words.as_slice() {
+            ["b", bag, nodes @ ..] => {
+                let bag = number(bag, b, line)?;
+                let nodes = nodes
+                    .iter()
+                    .map(|word| number(word, n, line))
+                    .collect::<Result<_, _>>()?;
+
+                if bags[bag].replace(nodes).is_some() {
+                    return Err(FormatError::Line(line));
+                }
+            }
+            [a, c] => {
+                let (a, c) = (number(a, b, line)?, number(c, b, line)?);
+                let (root_a, root_c) = (root(&mut roots, a), root(&mut roots, c));
+
+                // the edge closes a cycle or is a loop
+                if root_a == root_c {
+                    return Err(FormatError::Line(line));
+                }
+
+                roots[root_a] = root_c;
+                edges.push((a, c));
+            }
+            _ => return Err(FormatError::Line(line)),
+        }
+    }
+
+    if edges.len() + 1 < b {
+        return Err(FormatError::Count);
+    }
+
+    let mut td = TreeDecomposition::default();
+
+    for bag in bags {
+        td.add_bag(bag.ok_or(FormatError::Count)?);
+    }
+    for (a, c) in edges {
+        td.add_edge(a, c);
+    }
+
+    if td.max_bag_size != w {
+        return Err(FormatError::Count);
+    }
+
+    Ok(td)
+}
+
+/// Writes a tree decomposition of the graph in the PACE `.td` format, see [read_pace_td].
+///
+/// The nodes are numbered like in [write_pace_gr].
+pub fn write_pace_td(td: &TreeDecomposition, graph: &UndirectedGraph) -> String {
+    let ids = ids(graph);
+    let bag_ids: HashMap<usize, usize> = td
+        .bags()
+        .iter()
+        .enumerate()
+        .map(|(i, bag)| (bag.id, i + 1))
+        .collect();
+    let mut text = format!(
+        "s td {} {} {}\n",
+        td.bags().len(),
+        td.max_bag_size,
+        graph.node_count()
+    );
+
+    for bag in td.bags() {
+        let mut nodes = bag
+            .vertex_set
+            .iter()
+            .map(|v| ids[&NodeIndex::new(*v)])
+            .collect::<Vec<_>>();
+        nodes.sort_unstable();
+        write!(text, "b {}", bag_ids[&bag.id]).unwrap();
+        for v in nodes {
+            write!(text, " {}", v).unwrap();
+        }
+        text.push('\n');
+    }
+
+    for bag in td.bags() {
+        let mut neighbors = bag
+            .neighbors
+            .iter()
+            .map(|neighbor| bag_ids[neighbor])
+            .filter(|&neighbor| neighbor > bag_ids[&bag.id])
+            .collect::<Vec<_>>();
+        neighbors.sort_unstable();
+        for neighbor in neighbors {
+            writeln!(text, "{} {}", bag_ids[&bag.id], neighbor).unwrap();
+        }
+    }
+
+    text
+}
+
+// Returns the numbered lines split into words, which are neither empty nor comments
+fn content_lines<'a>(
+    text: &'a str,
+    comment: &'a str,
+) -> impl Iterator<Item = (usize, Vec<&'a str>)> + 'a {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.split_whitespace().collect::<Vec<_>>()))
+        .filter(move |(_, words)| matches!(words.first(), Some(word) if *word != comment))
+}
+
+// Returns the numbers of the header line starting with the given words
+fn header<const N: usize>(
+    line: Option<(usize, Vec<&str>)>,
+    prefix: &[&str],
+) -> Result<[usize; N], FormatError> {
+    let (_, words) = line.ok_or(FormatError::Header)?;
+
+    if words.len() != prefix.len() + N || words[..prefix.len()] != *prefix {
+        return Err(FormatError::Header);
+    }
+
+    let mut numbers = [0; N];
+    for (number, word) in numbers.iter_mut().zip(&words[prefix.len()..]) {
+        *number = word.parse().map_err(|_| FormatError::Header)?;
+    }
+
+    Ok(numbers)
+}
+
+fn read_edges<'a>(
+    lines: impl Iterator<Item = (usize, Vec<&'a str>)>,
+    n: usize,
+    m: usize,
+    edge: impl Fn(&[&'a str]) -> Option<(&'a str, &'a str)>,
+) -> Result<UndirectedGraph, FormatError> {
+    let mut graph = empty_graph(n);
+    let mut edges = 0;
+
+    for (line, words) in lines {
+        let (u, v) = edge(&words).ok_or(FormatError::Line(line))?;
+        let (u, v) = (node(u, n, line)?, node(v, n, line)?);
+
+        if u == v {
+            return Err(FormatError::Line(line));
+        }
+
+        graph.update_edge(u, v, ());
+        edges += 1;
+    }
+
+    if edges != m {
+        return Err(FormatError::Count);
+    }
+
+    Ok(graph)
+}
+
+fn write_edges(graph: &UndirectedGraph, text: &mut String, prefix: &str) {
+    let ids = ids(graph);
+
+    for e in graph.edge_indices() {
+        let (u, v) = graph.edge_endpoints(e).unwrap();
+        writeln!(text, "{}{} {}", prefix, ids[&u], ids[&v]).unwrap();
+    }
+}
+
+fn empty_graph(n: usize) -> UndirectedGraph {
+    let mut graph = UndirectedGraph::with_capacity(n, 0);
+
+    for _ in 0..n {
+        graph.add_node(());
+    }
+
+    graph
+}
+
+// Returns the number from 1 to `n` of every node
+fn ids(graph: &UndirectedGraph) -> HashMap<NodeIndex, usize> {
+    graph
+        .node_indices()
+        .enumerate()
+        .map(|(i, v)| (v, i + 1))
+        .collect()
+}
+
+// Parses a number from 1 to `n` and returns it starting from 0
+fn number(word: &str, n: usize, line: usize) -> Result<usize, FormatError> {
+    match word.parse::<usize>() {
+        Ok(number) if (1..=n).contains(&number) => Ok(number - 1),
+        _ => Err(FormatError::Line(line)),
+    }
+}
+
+// Returns the representative of the tree containing the bag
+fn root(roots: &mut [usize], bag: usize) -> usize {
+    let mut root = bag;
+
+    while roots[root] != root {
+        roots[root] = roots[roots[root]];
+        root = roots[root];
+    }
+
+    root
+}
+
+fn node(word: &str, n: usize, line: usize) -> Result<NodeIndex, FormatError> {
+    number(word, n, line).map(NodeIndex::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        read_dimacs, read_metis, read_pace_gr, read_pace_td, write_dimacs, write_metis,
+        write_pace_gr, write_pace_td, FormatError,
+    };
+    use crate::algorithm::dynamic_programming::solve::{dp_solve, DpProblem};
+    use crate::generation::partial_k_tree::generate_petgraph;
+    use crate::utils::convert::{to_hash_map_graph, UndirectedGraph};
+    use crate::utils::max_independent_set::brute_force_max_independent_set;
+    use arboretum_td::tree_decomposition::TreeDecomposition;
+    use fxhash::FxHashSet;
+    use petgraph::stable_graph::NodeIndex;
+    use std::collections::HashSet;
+
+    fn edges(graph: &UndirectedGraph) -> HashSet<(usize, usize)> {
+        graph
+            .edge_indices()
+            .map(|e| graph.edge_endpoints(e).unwrap())
+            .map(|(u, v)| (u.index().min(v.index()), u.index().max(v.index())))
+            .collect()
+    }
+
+    #[test]
+    fn dimacs() {
+        let graph = read_dimacs("c a triangle\np edge 4 3\ne 1 2\ne 2 3\n\ne 3 1\n").unwrap();
+
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(edges(&graph), HashSet::from([(0, 1), (1, 2), (0, 2)]));
+        assert_eq!(
+            edges(&read_dimacs(&write_dimacs(&graph)).unwrap()),
+            edges(&graph)
+        );
+        assert_eq!(read_dimacs("e 1 2\n").unwrap_err(), FormatError::Header);
+        assert_eq!(
+            read_dimacs("p edge 2 1\ne 1 3\n").unwrap_err(),
+            FormatError::Line(2)
+        );
+        assert_eq!(
+            read_dimacs("p edge 2 2\ne 1 2\n").unwrap_err(),
+            FormatError::Count
+        );
+        assert_eq!(
+            read_dimacs("p edge 2 1\ne 2 2\n").unwrap_err(),
+            FormatError::Line(2)
+        );
+    }
+
+    #[test]
+    fn pace_gr() {
+        let graph = read_pace_gr("p tw 5 4\n1 2\nc comment\n2 3\n3 4\n4 5\n").unwrap();
+
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), 4);
+        assert_eq!(write_pace_gr(&graph), "p tw 5 4\n1 2\n2 3\n3 4\n4 5\n");
+        assert_eq!(
+            read_pace_gr("p tw 2 1\n1 2 3\n").unwrap_err(),
+            FormatError::Line(2)
+        );
+    }
+
+    #[test]
+    fn metis() {
+        // the second node has weight 5, the edges have weights
+        let input = "% comment\n4 2 011\n1 2 7 3 1\n5 1 7\n1 1 1\n1\n";
+        let graph = read_metis(input).unwrap();
+
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(edges(&graph), HashSet::from([(0, 1), (0, 2)]));
+        assert_eq!(read_metis("4 3 011\n").unwrap_err(), FormatError::Count);
+
+        let graph = read_metis("3 2\n2\n1 3\n2\n").unwrap();
+        assert_eq!(write_metis(&graph), "3 2\n2\n3 1\n2\n");
+        assert_eq!(
+            edges(&read_metis(&write_metis(&graph)).unwrap()),
+            edges(&graph)
+        );
+        assert_eq!(
+            read_metis("2 1\n2\n1\n3\n").unwrap_err(),
+            FormatError::Count
+        );
+    }
+
+    #[test]
+    fn pace_td() {
+        let (graph, td) = generate_petgraph(16, 3, 0.6, Some(4));
+        let text = write_pace_td(&td, &graph);
+        let read = read_pace_td(&text).unwrap();
+
+        assert!(read.verify(&to_hash_map_graph(&graph)).is_ok());
+        assert_eq!(write_pace_td(&read, &graph), text);
+        assert_eq!(
+            dp_solve(&graph, Some(read), &DpProblem::max_independent_set()).len(),
+            brute_force_max_independent_set(&to_hash_map_graph(&graph)).len()
+        );
+        assert_eq!(
+            read_pace_td("s td 2 2 3\nb 1 1 2\n").unwrap_err(),
+            FormatError::Count
+        );
+        assert_eq!(
+            read_pace_td("s td 1 1 3\nb 1 4\n").unwrap_err(),
+            FormatError::Line(2)
+        );
+        assert_eq!(
+            read_pace_td("s td 3 2 3\nb 1 1 2\nb 2 2 3\nb 3 3\n1 2\n").unwrap_err(),
+            FormatError::Count
+        );
+        assert_eq!(
+            read_pace_td("s td 2 2 3\nb 1 1 2\nb 2 2 3\n1 2\n2 1\n").unwrap_err(),
+            FormatError::Line(5)
+        );
+        assert_eq!(
+            read_pace_td("s td 1 2 2\nb 1 1 2\n1 1\n").unwrap_err(),
+            FormatError::Line(3)
+        );
+    }
+
+    #[test]
+    fn pace_td_with_removed_nodes() {
+        let mut graph = UndirectedGraph::from_edges([(0, 1), (1, 2), (2, 3)]);
+        graph.remove_node(NodeIndex::new(0));
+        let mut td = TreeDecomposition::default();
+        let a = td.add_bag(FxHashSet::from_iter([1, 2]));
+        let b = td.add_bag(FxHashSet::from_iter([2, 3]));
+        td.add_edge(a, b);
+
+        assert_eq!(
+            write_pace_td(&td, &graph),
+            "s td 2 2 3\nb 1 1 2\nb 2 2 3\n1 2\n"
+        );
+        assert_eq!(write_pace_gr(&graph), "p tw 3 2\n1 2\n2 3\n");
+    }
+}
@@ -1,10 +1,11 @@
 //! Contains a linked implementation of the DCEL trait
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::{cell::RefCell, cmp::PartialEq, fmt::Debug, hash::Hash, rc::Rc};
 
-use dot::GraphWalk;
-
-use super::graph_dcel::{Dart, Face, GraphDCEL, Vertex};
+use super::graph_dcel::{dart_removal, Dart, Face, GraphDCEL, Vertex};
+use super::list_graph::ListGraph;
+use crate::utils::convert::UndirectedGraph;
+use petgraph::graph::NodeIndex;
 
 macro_rules! impl_non_recursive_eq {
     ($struct:ident) => {
@@ -105,7 +106,11 @@ impl LinkVertex {
 impl_inner_debug!(LinkVertex);
 impl_hash_and_eq!(LinkVertex);
 impl_ord!(LinkVertex);
-impl Vertex for LinkVertex {}
+impl Vertex for LinkVertex {
+    fn get_id(&self) -> usize {
+        LinkVertex::get_id(self)
+    }
+}
 
 #[derive(Default, Clone)]
 struct LinkDartStructure {
@@ -209,12 +214,18 @@ impl LinkFace {
     }
 }
 
-/// A linked implementation of the DCEL trait
-pub struct LinkGraph {
+/// A linked implementation of the DCEL trait.
+///
+/// Vertexes, darts and faces can carry weights of type `N`, `E` and `F`.
+pub struct LinkGraph<N = (), E = (), F = ()> {
     id_counter: usize,
     vertexes: Vec<LinkVertex>,
     darts: Vec<LinkDart>,
     faces: Vec<LinkFace>,
+    outer_face: Option<LinkFace>,
+    vertex_weights: HashMap<usize, N>,
+    dart_weights: HashMap<usize, E>,
+    face_weights: HashMap<usize, F>,
     #[cfg(feature = "debug_link_graph_panic_on_double_edges")]
     created_darts: HashSet<(usize, usize)>,
 }
@@ -241,7 +252,7 @@ impl<T: Clone> Iterator for LinkGraphIter<T> {
     }
 }
 
-impl
+impl<N, E, F>
     GraphDCEL<
         LinkVertex,
         LinkDart,
@@ -249,8 +260,12 @@ impl
         LinkGraphIter<LinkVertex>,
         LinkGraphIter<LinkDart>,
         LinkGraphIter<LinkFace>,
-    > for LinkGraph
+    > for LinkGraph<N, E, F>
 {
+    type VertexWeight = N;
+    type DartWeight = E;
+    type FaceWeight = F;
+
     fn get_vertexes(&self) -> LinkGraphIter<LinkVertex> {
         LinkGraphIter::new(self.vertexes.clone())
     }
@@ -258,7 +273,13 @@ impl
         LinkGraphIter::new(self.darts.clone())
     }
     fn get_faces(&self) -> LinkGraphIter<LinkFace> {
-        LinkGraphIter::new(self.faces.clone())
+        LinkGraphIter::new(
+            self.faces
+                .iter()
+                .filter(|face| self.is_face(face))
+                .cloned()
+                .collect(),
+        )
     }
 
     fn vertex_count(&self) -> usize {
@@ -274,23 +295,11 @@ impl
     }
 
     fn face_count(&self) -> usize {
-        self.faces.len()
+        self.faces.iter().filter(|face| self.is_face(face)).count()
     }
 
     fn face_vertex_count(&self, face: &LinkFace) -> usize {
-        let mut count = 1;
-        let dart = self.dart_face(face);
-        let mut current_dart = dart.clone();
-
-        loop {
-            current_dart = self.next(&current_dart);
-
-            if current_dart == dart {
-                break count;
-            }
-
-            count += 1;
-        }
+        self.face_darts(face).count()
     }
 
     fn neighbors_count(&self, vertex: &LinkVertex) -> usize {
@@ -298,36 +307,14 @@ impl
     }
 
     fn neighbors(&self, vertex: &LinkVertex) -> Vec<LinkVertex> {
-        let mut current_dart = self.dart_vertex(vertex);
-        let first_dart = current_dart.clone();
-        let mut current_neighbor = self.dart_target(&current_dart);
-        let mut result = vec![];
-        loop {
-            result.push(current_neighbor);
-            let twin_dart = self.twin(&current_dart);
-            current_dart = self.next(&twin_dart);
-            if current_dart == first_dart {
-                break;
-            }
-            current_neighbor = self.dart_target(&current_dart);
-        }
-        result
+        self.vertex_rotation(vertex)
+            .map(|dart| self.dart_target(&dart))
+            .collect()
     }
 
     fn get_dart(&self, vertex: &LinkVertex, target: &LinkVertex) -> Option<LinkDart> {
-        let first_dart = self.dart_vertex(vertex);
-        let mut dart = first_dart.clone();
-
-        loop {
-            if &self.target(&dart.clone()) == target {
-                break Some(dart);
-            }
-
-            dart = self.next(&self.twin(&dart));
-            if dart.clone() == first_dart.clone() {
-                break None;
-            }
-        }
+        self.vertex_rotation(vertex)
+            .find(|dart| &self.dart_target(dart) == target)
     }
 
     fn dart_vertex(&self, vertex: &LinkVertex) -> LinkDart {
@@ -378,27 +365,232 @@ impl
         self.new_face(dart)
     }
 
-    fn set_face(&self, dart: &LinkDart, face: LinkFace) {
+    fn set_face(&mut self, dart: &LinkDart, face: LinkFace) {
         dart.0.borrow_mut().face = Some(face);
     }
 
+    fn change_face(
+        &mut self,
+        dart: &LinkDart,
+        face: Option<LinkFace>,
+        prev: Option<LinkDart>,
+        next: Option<LinkDart>,
+    ) {
+        dart.clone().change_face(face, prev, next);
+    }
+
+    fn remove_edge(&mut self, from: &LinkVertex, dart: LinkDart) -> (LinkDart, LinkDart) {
+        LinkGraph::remove_edge(self, from, dart)
+    }
+
+    fn vertex_weight(&self, vertex: &LinkVertex) -> Option<&N> {
+        self.vertex_weights.get(&vertex.get_id())
+    }
+
+    fn set_vertex_weight(&mut self, vertex: &LinkVertex, weight: N) -> Option<N> {
+        self.vertex_weights.insert(vertex.get_id(), weight)
+    }
+
+    fn dart_weight(&self, dart: &LinkDart) -> Option<&E> {
+        self.dart_weights.get(&dart.get_id())
+    }
+
+    fn set_dart_weight(&mut self, dart: &LinkDart, weight: E) -> Option<E> {
+        self.dart_weights.insert(dart.get_id(), weight)
+    }
+
+    fn face_weight(&self, face: &LinkFace) -> Option<&F> {
+        self.face_weights.get(&face.get_id())
+    }
+
+    fn set_face_weight(&mut self, face: &LinkFace, weight: F) -> Option<F> {
+        self.face_weights.insert(face.get_id(), weight)
+    }
+
+    fn split_face(&mut self, a: &LinkDart, b: &LinkDart) -> (LinkDart, LinkDart) {
+        assert!(
+            a != b && self.face(a) == self.face(b),
+            "darts have to be different darts of the same face"
+        );
+        let (from, to) = (self.dart_target(a), self.dart_target(b));
+        assert!(from != to, "loops are not supported");
+        let (a_next, b_next) = (self.next(a), self.next(b));
+        let face = self.face(a);
+        let dart = LinkDart::new(self.next_id(), to);
+        let twin = LinkDart::new(self.next_id(), from);
+
+        Self::set_twins(&dart, &twin);
+        Self::link(a, &dart);
+        Self::link(&dart, &b_next);
+        Self::link(b, &twin);
+        Self::link(&twin, &a_next);
+        self.darts.push(dart.clone());
+        self.darts.push(twin.clone());
+
+        // the face keeps the cycle containing its dart, the other cycle gets a new face
+        let face_dart = self.dart_face(&face);
+        let cycle = self.set_cycle_face(&dart, &face);
+        self.set_cycle_face(&twin, &face);
+        face.0.borrow_mut().dart = face_dart.clone();
+        let start = if cycle.contains(&face_dart) {
+            twin.clone()
+        } else {
+            dart.clone()
+        };
+        let new_face = self.new_face(start.clone());
+        self.set_cycle_face(&start, &new_face);
+
+        (dart, twin)
+    }
+
+    fn merge_faces(&mut self, dart: &LinkDart) -> LinkFace {
+        let twin = self.twin(dart);
+        assert!(
+            self.face(dart) != self.face(&twin),
+            "the dart has to separate two faces"
+        );
+        let prev = self.prev(dart);
+
+        self.remove_darts(vec![dart.clone(), twin], false);
+        self.face(&prev)
+    }
+
+    fn subdivide_edge(&mut self, dart: &LinkDart) -> LinkVertex {
+        let twin = self.twin(dart);
+        let (from, to) = (self.dart_target(&twin), self.dart_target(dart));
+        let (dart_next, twin_next) = (self.next(dart), self.next(&twin));
+        let vertex = self.new_vertex();
+        let dart_end = LinkDart::new(self.next_id(), to);
+        let twin_end = LinkDart::new(self.next_id(), from);
+
+        dart.0.borrow_mut().target = vertex.clone();
+        twin.0.borrow_mut().target = vertex.clone();
+        dart_end.0.borrow_mut().face = dart.0.borrow().face.clone();
+        twin_end.0.borrow_mut().face = twin.0.borrow().face.clone();
+        Self::set_twins(dart, &twin_end);
+        Self::set_twins(&twin, &dart_end);
+        Self::link(dart, &dart_end);
+        Self::link(&dart_end, &dart_next);
+        Self::link(&twin, &twin_end);
+        Self::link(&twin_end, &twin_next);
+        vertex.0.borrow_mut().dart = Some(dart_end.clone());
+        self.darts.push(dart_end);
+        self.darts.push(twin_end);
+
+        vertex
+    }
+
+    fn contract_edge(&mut self, dart: &LinkDart) -> LinkVertex {
+        let twin = self.twin(dart);
+        let (vertex, removed) = (self.dart_target(&twin), self.dart_target(dart));
+        assert!(vertex != removed, "loops can not be contracted");
+
+        self.remove_darts(vec![dart.clone(), twin], true);
+
+        let removed_dart = removed.0.borrow_mut().dart.take();
+
+        if let Some(first) = removed_dart {
+            for dart in self.rotation(&first) {
+                self.twin(&dart).0.borrow_mut().target = vertex.clone();
+            }
+
+            let vertex_dart = vertex.0.borrow().dart.clone();
+            vertex.0.borrow_mut().dart = vertex_dart.or(Some(first));
+        }
+
+        self.vertexes.retain(|v| v != &removed);
+        self.vertex_weights.remove(&removed.get_id());
+
+        let vertex_dart = vertex.0.borrow().dart.clone();
+
+        if let Some(first) = vertex_dart {
+            let mut neighbors = HashSet::new();
+
+            for dart in self.rotation(&first) {
+                if !neighbors.insert(self.dart_target(&dart)) {
+                    self.merge_faces(&dart);
+                }
+            }
+        }
+
+        vertex
+    }
+
+    fn remove_vertex(&mut self, vertex: &LinkVertex) {
+        let vertex_dart = vertex.0.borrow().dart.clone();
+
+        if let Some(first) = vertex_dart {
+            let darts = self
+                .rotation(&first)
+                .into_iter()
+                .flat_map(|dart| [self.twin(&dart), dart])
+                .collect();
+            self.remove_darts(darts, false);
+        }
+
+        self.vertexes.retain(|v| v != vertex);
+        self.vertex_weights.remove(&vertex.get_id());
+    }
+
     fn vertex_by_id(&self, id: usize) -> Option<LinkVertex> {
         self.vertexes.iter().find(|v| v.get_id() == id).cloned()
     }
 }
 
 impl LinkGraph {
-    /// Returns a new empty ListGraph
+    /// Returns a new empty LinkGraph without weights
     pub fn new() -> LinkGraph {
-        LinkGraph {
-            id_counter: 0,
-            vertexes: Vec::new(),
-            darts: Vec::new(),
-            faces: Vec::new(),
-            #[cfg(feature = "debug_link_graph_panic_on_double_edges")]
-            created_darts: HashSet::new(),
+        LinkGraph::default()
+    }
+
+    /// Creates a LinkGraph from the rotation system of the given ListGraph.
+    ///
+    /// The vertex ids match the node ids of the ListGraph and the faces are given by the cyclic
+    /// order of the edges around every node.
+    pub fn from_list_graph(graph: &ListGraph) -> LinkGraph {
+        let mut lg = LinkGraph::new();
+        let vertexes = graph
+            .node_indexes()
+            .map(|_| lg.new_vertex())
+            .collect::<Vec<_>>();
+        let mut darts = HashMap::new();
+
+        for edge in graph.edge_indexes() {
+            let (from, to) = graph.edge(edge).unwrap();
+            let dart = LinkDart::new(lg.next_id(), vertexes[to].clone());
+            let twin = LinkDart::new(lg.next_id(), vertexes[from].clone());
+            dart.0.borrow_mut().twin = Some(twin.clone());
+            twin.0.borrow_mut().twin = Some(dart.clone());
+            lg.darts.push(dart.clone());
+            lg.darts.push(twin.clone());
+            darts.insert((edge, from), dart);
+            darts.insert((edge, to), twin);
+        }
+
+        for ((edge, from), dart) in &darts {
+            let to = graph.opposite(*from, *edge).unwrap();
+            let next = darts[&(graph.cyclic_incident_succ(*edge, to).unwrap(), to)].clone();
+            next.0.borrow_mut().prev = Some(dart.clone());
+            dart.0.borrow_mut().next = Some(next);
+        }
+
+        for (node, vertex) in vertexes.iter().enumerate() {
+            if let Some(edge) = graph.edges(node).unwrap().first() {
+                vertex.0.borrow_mut().dart = Some(darts[&(*edge, node)].clone());
+            }
+        }
+
+        for dart in lg.darts.clone() {
+            if dart.0.borrow().face.is_none() {
+                lg.auto_face_dart(dart);
+            }
         }
+
+        lg
     }
+}
+
+impl<N, E, F> LinkGraph<N, E, F> {
     fn next_id(&mut self) -> usize {
         let id = self.id_counter;
         self.id_counter += 1;
@@ -441,6 +633,18 @@ impl LinkGraph {
                 .unwrap_or_else(|| ld.clone()),
         };
 
+        // the darts between prev and next of a partially linked face are moved to a cycle
+        // of their own, so that all cycles stay closed
+        let (prev_next, next_prev) = (
+            prev_dart.0.borrow().next.clone(),
+            next_dart.0.borrow().prev.clone(),
+        );
+        if let (Some(prev_next), Some(next_prev)) = (prev_next, next_prev) {
+            if prev_next != next_dart {
+                Self::link(&next_prev, &prev_next);
+            }
+        }
+
         next_dart.0.borrow_mut().prev = Some(ld.clone());
         ld.0.borrow_mut().next = Some(next_dart);
         prev_dart.0.borrow_mut().next = Some(ld.clone());
@@ -466,6 +670,106 @@ impl LinkGraph {
         lv
     }
 
+    fn link(prev: &LinkDart, next: &LinkDart) {
+        prev.0.borrow_mut().next = Some(next.clone());
+        next.0.borrow_mut().prev = Some(prev.clone());
+    }
+
+    fn set_twins(dart: &LinkDart, twin: &LinkDart) {
+        dart.0.borrow_mut().twin = Some(twin.clone());
+        twin.0.borrow_mut().twin = Some(dart.clone());
+    }
+
+    // returns the darts starting at the source of the given dart in cyclic order
+    fn rotation(&self, first: &LinkDart) -> Vec<LinkDart> {
+        let mut rotation = vec![first.clone()];
+        let mut current = self.next(&self.twin(first));
+
+        while &current != first {
+            rotation.push(current.clone());
+            current = self.next(&self.twin(&current));
+        }
+
+        rotation
+    }
+
+    // assigns the face to all darts of the cycle containing `start` and returns the cycle
+    fn set_cycle_face(&self, start: &LinkDart, face: &LinkFace) -> Vec<LinkDart> {
+        let mut cycle = vec![];
+        let mut current = start.clone();
+
+        while {
+            current.0.borrow_mut().face = Some(face.clone());
+            cycle.push(current.clone());
+            current = self.next(&current);
+            &current != start
+        } {}
+
+        face.0.borrow_mut().dart = start.clone();
+        cycle
+    }
+
+    // removes the given darts, which have to contain the twin of every dart, and reassigns
+    // the faces of the remaining darts of the affected cycles. If `contract` is set, the
+    // removed edges are contracted instead of deleted.
+    fn remove_darts(&mut self, darts: Vec<LinkDart>, contract: bool) {
+        let removal = dart_removal(self, &darts, contract);
+        let removed = darts.iter().cloned().collect::<HashSet<_>>();
+
+        for (vertex, dart) in removal.vertex_darts {
+            vertex.0.borrow_mut().dart = dart;
+        }
+
+        for (prev, next) in &removal.links {
+            Self::link(prev, next);
+        }
+
+        for dart in &darts {
+            let mut dart_ref = dart.0.borrow_mut();
+            dart_ref.twin.take();
+            dart_ref.next.take();
+            dart_ref.prev.take();
+            dart_ref.face.take();
+        }
+
+        self.darts.retain(|dart| !removed.contains(dart));
+        for dart in &darts {
+            self.dart_weights.remove(&dart.get_id());
+        }
+
+        let mut faces = removal.faces;
+        faces.reverse();
+        let mut visited = HashSet::new();
+        let mut outer_face = self.outer_face.take();
+
+        for (start, _) in removal.links {
+            if visited.contains(&start) {
+                continue;
+            }
+
+            let old_face = start.0.borrow().face.clone();
+            let face = match faces.pop() {
+                Some(face) => face,
+                None => self.new_face(start.clone()),
+            };
+            visited.extend(self.set_cycle_face(&start, &face));
+
+            // the outer face moves to the first cycle which was part of it
+            if old_face.is_some() && old_face == outer_face {
+                self.outer_face = Some(face);
+                outer_face = None;
+            }
+        }
+
+        self.outer_face = self.outer_face.take().or(outer_face);
+
+        for face in &faces {
+            self.face_weights.remove(&face.get_id());
+        }
+
+        self.faces.retain(|face| !faces.contains(face));
+    }
+
     /// Adds an edge to the graph
     pub fn new_edge(
         &mut self,
@@ -535,7 +839,8 @@ impl LinkGraph {
         if let Some(dart_pos) = self.darts.iter().position(|d| &dart == d) {
             self.darts.remove(dart_pos);
         }
-        from.0.borrow_mut().dart = if self.target(&next.clone().unwrap()) == *from {
+        self.dart_weights.remove(&dart.get_id());
+        from.0.borrow_mut().dart = if self.dart_target(&next.clone().unwrap()) == *from {
             Some(self.twin(&next.unwrap()))
         } else {
             next
@@ -552,6 +857,20 @@ impl LinkGraph {
             drop(dart_ref);
             (twin, next)
         };
+        // the face left behind by the edge stays the outer face if one of its sides was
+        let outer = self.outer_face.is_some()
+            && [Some(&dart), twin.as_ref()]
+                .into_iter()
+                .flatten()
+                .any(|d| d.0.borrow().face == self.outer_face);
+        // the faces of the removed darts and the dart following the twin, whose cycle is not
+        // relabeled if the edge separates it from the cycle of `next`
+        let old_faces = [Some(&dart), twin.as_ref()]
+            .into_iter()
+            .flatten()
+            .filter_map(|d| d.0.borrow().face.clone())
+            .collect::<Vec<_>>();
+        let other = twin.as_ref().and_then(|twin| twin.0.borrow().next.clone());
         let dart_data = {
             let dart_borrow = dart.0.borrow();
             let dart_data = dart_borrow.clone();
@@ -559,7 +878,7 @@ impl LinkGraph {
             dart_data
         };
         let (twin, twin_data) = if let Some(twin) = twin {
-            let twin_from = self.target(&dart);
+            let twin_from = self.dart_target(&dart);
             let twin_data = twin.0.borrow().clone();
             (self.remove_dart(&twin_from, twin, dart_data), twin_data)
         } else {
@@ -575,12 +894,39 @@ impl LinkGraph {
             self.created_darts.remove(&insert_touple);
         }
         let res = (self.remove_dart(from, dart, twin_data), twin);
-        if let Some(next) = next {
-            self.auto_face_dart(next);
+        let next = match next {
+            Some(next) => next,
+            None => return res,
+        };
+        self.auto_face_dart(next.clone());
+        if outer {
+            self.outer_face = Some(self.face(&next));
+        }
+
+        // an old face stays in use only as the face of the cycle which was not relabeled
+        let kept = other
+            .filter(|other| *other != res.0 && *other != res.1)
+            .and_then(|other| {
+                let face = other.0.borrow().face.clone()?;
+                face.0.borrow_mut().dart = other;
+                Some(face)
+            });
+        for face in old_faces {
+            if Some(&face) != kept.as_ref() {
+                self.remove_face(&face);
+            }
         }
         res
     }
 
+    // Removes the face, which must not be the face of any dart
+    fn remove_face(&mut self, face: &LinkFace) {
+        if let Some(position) = self.faces.iter().position(|f| f == face) {
+            self.faces.remove(position);
+            self.face_weights.remove(&face.get_id());
+        }
+    }
+
     fn validate_darts(&self) {
         for dart in self.get_darts() {
             let dart_inner = dart.0.borrow();
@@ -611,7 +957,7 @@ impl LinkGraph {
     }
 
     fn validate_circle<W: Fn(&LinkDart) -> LinkDart>(&self, description: &str, walk_fn: W) {
-        let max = self.edge_count() + 1;
+        let max = self.dart_count();
         for dart in &self.darts {
             let mut current_dart = dart.clone();
             let mut i = 0;
@@ -660,6 +1006,32 @@ impl LinkGraph {
         }
     }
 
+    /// Returns the outer face of the embedding.
+    ///
+    /// If no outer face was set or the set face has been removed, the face of the first dart
+    /// is returned.
+    pub fn outer_face(&self) -> Option<LinkFace> {
+        self.outer_face
+            .clone()
+            .filter(|face| self.is_face(face))
+            .or_else(|| self.darts.first().map(|dart| self.face(dart)))
+    }
+
+    // Returns true if the face is still the face of its dart. The darts of a face can be
+    // assigned to other faces by `change_face`, which leaves the face unused.
+    fn is_face(&self, face: &LinkFace) -> bool {
+        face.0.borrow().dart.0.borrow().face.as_ref() == Some(face)
+    }
+
+    /// Makes the given face the outer face of the embedding
+    pub fn set_outer_face(&mut self, face: &LinkFace) {
+        assert!(
+            self.face(&self.dart_face(face)) == *face,
+            "face is not part of the graph"
+        );
+        self.outer_face = Some(face.clone());
+    }
+
     /// Validates the integrity of the graph. Panics if graph is invalid. Therefor mainly usefully for debugging.
     pub fn validate(&self) {
         self.validate_darts();
@@ -693,16 +1065,144 @@ impl LinkGraph {
                 todo_darts.remove(&dart);
             }
         }
+        self.remove_unused_faces();
+    }
+
+    // Removes the faces which are no longer the face of any dart, so that `get_faces` only
+    // returns faces of the graph, and moves the dart of every remaining face onto the face.
+    // This sweeps all darts, so it is only used after relabeling all faces.
+    fn remove_unused_faces(&mut self) {
+        let face_darts = self
+            .darts
+            .iter()
+            .filter_map(|dart| {
+                dart.0
+                    .borrow()
+                    .face
+                    .clone()
+                    .map(|face| (face, dart.clone()))
+            })
+            .collect::<HashMap<_, _>>();
+
+        for (face, dart) in &face_darts {
+            if self.dart_face(face).0.borrow().face.as_ref() != Some(face) {
+                face.0.borrow_mut().dart = dart.clone();
+            }
+        }
+
+        let face_weights = &mut self.face_weights;
+        self.faces.retain(|face| {
+            let used = face_darts.contains_key(face);
+            if !used {
+                face_weights.remove(&face.get_id());
+            }
+            used
+        });
+    }
+
+    /// Returns the rotation system of this LinkGraph as a ListGraph.
+    ///
+    /// The vertexes are numbered in the order of `get_vertexes`, so the ids are kept for
+    /// a LinkGraph created by `from_list_graph`.
+    pub fn to_list_graph(&self) -> ListGraph {
+        let nodes = self
+            .vertexes
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.clone(), i))
+            .collect::<HashMap<_, _>>();
+        let mut edge_ids = HashMap::new();
+        let mut edges = vec![];
+
+        for dart in &self.darts {
+            if !edge_ids.contains_key(dart) {
+                let twin = self.twin(dart);
+                edge_ids.insert(dart.clone(), edges.len());
+                edge_ids.insert(twin.clone(), edges.len());
+                edges.push((
+                    nodes[&self.dart_target(&twin)],
+                    nodes[&self.dart_target(dart)],
+                ));
+            }
+        }
+
+        let rotations = self
+            .vertexes
+            .iter()
+            .map(|v| {
+                // a vertex without edges has no rotation
+                if v.0.borrow().dart.is_none() {
+                    return vec![];
+                }
+
+                self.vertex_rotation(v)
+                    .map(|dart| edge_ids[&dart])
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        ListGraph::from_edges_node_list(
+            edges.iter(),
+            rotations
+                .iter()
+                .map(|r| r.as_slice())
+                .collect::<Vec<_>>()
+                .iter(),
+        )
+    }
+
+    /// Returns the graph as a petgraph StableGraph where the node indices match the vertex ids
+    pub fn to_pet_graph(&self) -> UndirectedGraph {
+        let mut graph = UndirectedGraph::default();
+        let ids = self
+            .vertexes
+            .iter()
+            .map(|v| v.get_id())
+            .collect::<HashSet<_>>();
+        let nodes = (0..ids.iter().max().map_or(0, |max| max + 1))
+            .map(|_| graph.add_node(()))
+            .collect::<Vec<_>>();
+
+        for node in nodes {
+            if !ids.contains(&node.index()) {
+                graph.remove_node(node);
+            }
+        }
+
+        for dart in &self.darts {
+            let twin = self.twin(dart);
+
+            if dart.get_id() < twin.get_id() {
+                graph.add_edge(
+                    NodeIndex::new(self.dart_target(&twin).get_id()),
+                    NodeIndex::new(self.dart_target(dart).get_id()),
+                    (),
+                );
+            }
+        }
+
+        graph
     }
 }
 
-impl Default for LinkGraph {
+impl<N, E, F> Default for LinkGraph<N, E, F> {
     fn default() -> Self {
-        LinkGraph::new()
+        LinkGraph {
+            id_counter: 0,
+            vertexes: Vec::new(),
+            darts: Vec::new(),
+            faces: Vec::new(),
+            outer_face: None,
+            vertex_weights: HashMap::new(),
+            dart_weights: HashMap::new(),
+            face_weights: HashMap::new(),
+            #[cfg(feature = "debug_link_graph_panic_on_double_edges")]
+            created_darts: HashSet::new(),
+        }
     }
 }
 
-impl Drop for LinkGraph {
+impl<N, E, F> Drop for LinkGraph<N, E, F> {
     fn drop(&mut self) {
         for vertex in &self.vertexes {
             vertex.0.borrow_mut().dart.take();
@@ -725,7 +1225,12 @@ mod tests {
     use std::{cmp::Ordering, collections::HashSet};
 
     use crate::data_structure::link_graph::example::three_ring_graph;
-    use crate::data_structure::{graph_dcel::GraphDCEL, link_graph::LinkGraph};
+    use crate::data_structure::{
+        graph_dcel::GraphDCEL, link_graph::LinkGraph, list_graph::ListGraph,
+    };
+    use crate::embedding::{index::Embedding, maximal_planar::index::MaximalPlanar};
+    use crate::generation::{geometric::embed, planar::generate};
+    use petgraph::graph::NodeIndex;
 
     fn example_graph() -> LinkGraph {
         let mut lg = LinkGraph::new();
@@ -971,6 +1476,41 @@ mod tests {
         assert_eq!(g.face_vertex_count(&f), 3);
     }
 
+    #[test]
+    fn face_queries() {
+        let g = example_graph();
+        let dart = g.get_darts().next().unwrap();
+        let face = g.face(&dart);
+        let vertices: Vec<_> = g.face_vertices(&face).collect();
+
+        assert_eq!(g.face_darts(&face).count(), 3);
+        assert!(g.face_darts(&face).all(|d| g.face(&d) == face));
+        assert_eq!(vertices.iter().collect::<HashSet<_>>().len(), 3);
+        assert_eq!(g.dart_cycle(dart.clone()).nth(1), Some(g.next(&dart)),);
+    }
+
+    #[test]
+    fn vertex_queries() {
+        let g = MaximalPlanar::embed(generate(30, Some(3)).to_pet_graph());
+
+        for v in g.get_vertexes() {
+            let rotation: Vec<_> = g.vertex_rotation(&v).collect();
+            let faces: Vec<_> = g.incident_faces(&v).collect();
+
+            assert!(rotation.iter().all(|d| g.dart_target(&g.twin(d)) == v));
+            assert_eq!(
+                rotation
+                    .iter()
+                    .map(|d| g.dart_target(d))
+                    .collect::<Vec<_>>(),
+                g.neighbors(&v)
+            );
+            // every vertex of a triangulation has as many incident faces as neighbors
+            assert_eq!(faces.len(), rotation.len());
+            assert!(faces.iter().all(|f| g.face_vertices(f).any(|u| u == v)));
+        }
+    }
+
     #[test]
     fn neighbors_count() {
         let g = example_graph();
@@ -1035,6 +1575,231 @@ mod tests {
         assert_eq!(darts[10].0.borrow().face, darts[1].0.borrow().face);
     }
 
+    fn check_euler(g: &LinkGraph) {
+        g.validate();
+        let faces = g
+            .get_darts()
+            .map(|d| g.face(&d))
+            .collect::<HashSet<_>>()
+            .len();
+        assert_eq!(faces + g.vertex_count(), g.edge_count() + 2);
+
+        for v in g.get_vertexes() {
+            let neighbors = g.neighbors(&v);
+            assert_eq!(
+                neighbors.iter().collect::<HashSet<_>>().len(),
+                neighbors.len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_mutable_operations() {
+        let mut g: LinkGraph = MaximalPlanar::embed(generate(30, Some(4)).to_pet_graph());
+        let mut vertex = None;
+
+        for i in 0..5 {
+            let (v, e) = (g.vertex_count(), g.edge_count());
+            let dart = g.get_darts().nth(7 * i).unwrap();
+            let new = g.subdivide_edge(&dart);
+            check_euler(&g);
+            assert_eq!(g.neighbors_count(&new), 2);
+            assert_eq!((g.vertex_count(), g.edge_count()), (v + 1, e + 1));
+            vertex = Some(new);
+        }
+
+        let e = g.edge_count();
+        let a = g.dart_vertex(&vertex.unwrap());
+        let b = g.next(&g.next(&a));
+        let (dart, twin) = g.split_face(&a, &b);
+        check_euler(&g);
+        assert_eq!(g.edge_count(), e + 1);
+        assert_eq!(g.next(&a), dart);
+        assert_eq!(g.next(&b), twin);
+        assert_ne!(g.face(&dart), g.face(&twin));
+
+        let face = g.merge_faces(&dart);
+        check_euler(&g);
+        assert_eq!(g.edge_count(), e);
+        assert_eq!(g.face(&a), face);
+        assert_eq!(g.face(&b), face);
+
+        let v = g.vertex_count();
+        let removed = g.get_vertexes().nth(3).unwrap();
+        g.remove_vertex(&removed);
+        check_euler(&g);
+        assert_eq!(g.vertex_count(), v - 1);
+        assert!(g
+            .get_vertexes()
+            .all(|u| !g.neighbors(&u).contains(&removed)));
+
+        for i in 0..g.vertex_count() - 3 {
+            let v = g.vertex_count();
+            let dart = g.get_darts().nth(11 * i % g.dart_count()).unwrap();
+            let removed = g.dart_target(&dart);
+            let vertex = g.contract_edge(&dart);
+            check_euler(&g);
+            assert_eq!(g.vertex_count(), v - 1);
+            assert!(g.get_vertexes().all(|u| u != removed));
+            assert!(!g.neighbors(&vertex).contains(&removed));
+        }
+    }
+
+    #[test]
+    fn test_contract_triangle() {
+        let mut g = example_graph();
+        let dart = g.get_darts().next().unwrap();
+
+        g.contract_edge(&dart);
+        g.validate();
+        assert_eq!(g.vertex_count(), 2);
+        assert_eq!(g.edge_count(), 1);
+        assert_eq!(g.get_faces().count(), 1);
+    }
+
+    #[test]
+    fn test_outer_face() {
+        let mut g = example_graph();
+        let darts: Vec<_> = g.get_darts().collect();
+        let inner = g.face(&darts[0]);
+        let outer = g.face(&g.twin(&darts[0]));
+
+        assert_eq!(g.outer_face(), Some(inner.clone()));
+        g.set_outer_face(&outer);
+        assert_eq!(g.outer_face(), Some(outer.clone()));
+
+        let (dart, _) = g.split_face(&g.twin(&darts[0]), &g.twin(&darts[1]));
+        assert!(g.face(&dart) == outer || g.face(&g.twin(&dart)) == outer);
+        assert_eq!(g.outer_face(), Some(outer));
+
+        // merging the outer face with a new face keeps it as outer face
+        let merged = g.merge_faces(&dart);
+        assert_eq!(g.outer_face(), Some(merged));
+        g.validate();
+
+        // removing an edge of the outer face makes the remaining face the outer face
+        let (dart, _) = g.split_face(&g.twin(&darts[0]), &g.twin(&darts[1]));
+        let inner = if g.face(&dart) == g.outer_face().unwrap() {
+            g.face(&g.twin(&dart))
+        } else {
+            g.face(&dart)
+        };
+        g.set_outer_face(&inner);
+        let from = g.dart_target(&g.twin(&dart));
+        let next = g.next(&dart);
+        g.remove_edge(&from, dart);
+        assert_eq!(g.outer_face(), Some(g.face(&next)));
+        g.validate();
+    }
+
+    #[test]
+    fn test_embedded_faces() {
+        for n in 4..12 {
+            let g: LinkGraph = MaximalPlanar::embed(generate(n, Some(n as u64)).to_pet_graph());
+            let faces = g.get_faces().collect::<HashSet<_>>();
+
+            // the embedding reassigns the darts of faces, which must not be returned anymore
+            assert_eq!(faces, g.get_darts().map(|d| g.face(&d)).collect());
+            assert_eq!(g.face_count(), faces.len());
+        }
+    }
+
+    #[test]
+    fn test_remove_edge_faces() {
+        let mut g = LinkGraph::from_list_graph(&generate(20, Some(1)));
+        let n = g.vertex_count();
+
+        for _ in 0..10 {
+            let dart = g.get_darts().next().unwrap();
+            let from = g.dart_target(&g.twin(&dart));
+            g.remove_edge(&from, dart);
+
+            // the graph stays connected, since it was triangulated
+            let faces = g.get_faces().collect::<HashSet<_>>();
+            assert_eq!(faces, g.get_darts().map(|d| g.face(&d)).collect());
+            assert!(faces.iter().all(|f| g.face(&g.dart_face(f)) == *f));
+            assert_eq!(g.face_count() + n, g.edge_count() + 2);
+        }
+    }
+
+    #[test]
+    fn test_remove_bridge_faces() {
+        let points = [(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0)];
+        let mut g = embed(&points, &[(0, 1), (1, 2), (2, 3)]).to_link_graph();
+        let (v1, v2) = (g.vertex_by_id(1).unwrap(), g.vertex_by_id(2).unwrap());
+        let dart = g.get_dart(&v1, &v2).unwrap();
+        g.remove_edge(&v1, dart);
+
+        // both paths keep a face of their own
+        let faces = g.get_faces().collect::<HashSet<_>>();
+        assert_eq!(faces.len(), 2);
+        assert_eq!(faces, g.get_darts().map(|d| g.face(&d)).collect());
+        assert!(faces.iter().all(|f| g.face(&g.dart_face(f)) == *f));
+        g.validate();
+    }
+
+    #[test]
+    fn test_from_list_graph() {
+        for graph in [
+            ListGraph::k4(),
+            generate(10, Some(1)),
+            generate(100, Some(2)),
+        ] {
+            let lg = LinkGraph::from_list_graph(&graph);
+            lg.validate();
+
+            let n = graph.node_indexes().count();
+            let e = graph.edge_indexes().count();
+            assert_eq!(lg.vertex_count(), n);
+            assert_eq!(lg.edge_count(), e);
+            assert_eq!(lg.face_count(), e + 2 - n);
+
+            for face in lg.get_faces() {
+                assert_eq!(lg.face_vertex_count(&face), 3);
+            }
+
+            for v in lg.get_vertexes() {
+                let neighbors = lg
+                    .neighbors(&v)
+                    .iter()
+                    .map(|u| u.get_id())
+                    .collect::<Vec<_>>();
+                assert_eq!(Some(neighbors), graph.neighbors(v.get_id()));
+            }
+
+            let back = lg.to_list_graph();
+
+            for node in graph.node_indexes() {
+                assert_eq!(back.neighbors(node), graph.neighbors(node));
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_pet_graph() {
+        let graph = generate(30, Some(3)).to_pet_graph();
+        let lg: LinkGraph = MaximalPlanar::embed(graph);
+        let pet_graph = lg.to_pet_graph();
+
+        assert_eq!(pet_graph.node_count(), lg.vertex_count());
+        assert_eq!(pet_graph.edge_count(), lg.edge_count());
+
+        for v in lg.get_vertexes() {
+            let mut neighbors = lg
+                .neighbors(&v)
+                .iter()
+                .map(|u| u.get_id())
+                .collect::<Vec<_>>();
+            let mut pet_neighbors = pet_graph
+                .neighbors(NodeIndex::new(v.get_id()))
+                .map(|u| u.index())
+                .collect::<Vec<_>>();
+            neighbors.sort();
+            pet_neighbors.sort();
+            assert_eq!(neighbors, pet_neighbors);
+        }
+    }
+
     #[cfg(feature = "debug_link_graph_panic_on_double_edges")]
     #[test]
     #[should_panic]
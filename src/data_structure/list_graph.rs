@@ -2,7 +2,9 @@
 //! for embedding maximal planar graphs](https://citeseerx.ist.psu.edu/viewdoc/download?doi=10.1.1.31.9303&rep=rep1&type=pdf)
 
 use petgraph::stable_graph::StableGraph;
+use std::collections::HashMap;
 
+use super::link_graph::LinkGraph;
 use crate::utils::convert::UndirectedGraph;
 
 /// The type of the edge id
@@ -55,6 +57,9 @@ impl ListGraph {
     ) -> ListGraph {
         let mut graph = ListGraph::from_edges(edges);
         for (i, node) in nodes.enumerate() {
+            if graph.nodes.len() < i + 1 {
+                graph.nodes.resize_with(i + 1, Vec::new);
+            }
             graph.nodes[i] = node
                 .iter()
                 .map(|edge_id| {
@@ -68,6 +73,109 @@ impl ListGraph {
         graph
     }
 
+    /// Creates a ListGraph from the faces of a planar embedding.
+    ///
+    /// Every face lists its nodes in the order of its boundary, the inner faces counterclockwise
+    /// and the outer face clockwise, so that every edge is traversed once in each direction.
+    /// Panics if the faces do not form a valid rotation system.
+    pub fn from_faces(faces: &[Vec<NodeId>]) -> ListGraph {
+        let mut edges = vec![];
+        let mut edge_ids = HashMap::new();
+        // maps the dart (v, u) to the node following u in the rotation of v
+        let mut succ = HashMap::new();
+
+        for face in faces {
+            for i in 0..face.len() {
+                let (u, v, w) = (
+                    face[i],
+                    face[(i + 1) % face.len()],
+                    face[(i + 2) % face.len()],
+                );
+
+                if succ.insert((v, u), w).is_some() {
+                    panic!("dart from {} to {} is contained in two faces", u, v);
+                }
+
+                edge_ids.entry((u.min(v), u.max(v))).or_insert_with(|| {
+                    edges.push((u.min(v), u.max(v)));
+                    edges.len() - 1
+                });
+            }
+        }
+
+        let node_count = edges.iter().map(|(_, v)| v + 1).max().unwrap_or(0);
+        let mut rotations = vec![vec![]; node_count];
+        let mut darts = succ.keys().copied().collect::<Vec<_>>();
+        darts.sort_unstable();
+
+        for &(v, first) in &darts {
+            if !rotations[v].is_empty() {
+                continue;
+            }
+
+            let mut u = first;
+
+            while {
+                rotations[v].push(edge_ids[&(u.min(v), u.max(v))]);
+                u = succ[&(v, u)];
+                u != first
+            } {}
+        }
+
+        let mut degrees = vec![0; node_count];
+
+        for &(v, _) in &darts {
+            degrees[v] += 1;
+        }
+
+        for (v, rotation) in rotations.iter().enumerate() {
+            assert_eq!(
+                rotation.len(),
+                degrees[v],
+                "faces do not form a valid rotation system at node {}",
+                v
+            );
+        }
+
+        ListGraph::from_edges_node_list(
+            edges.iter(),
+            rotations
+                .iter()
+                .map(|rotation| rotation.as_slice())
+                .collect::<Vec<_>>()
+                .iter(),
+        )
+    }
+
+    /// Creates a ListGraph from its edges and the ids of the edges around every node in cyclic
+    /// order, without searching the adjacency lists.
+    pub(crate) fn from_rotation_system(
+        edges: Vec<(NodeId, NodeId)>,
+        rotations: Vec<Vec<EdgeId>>,
+    ) -> ListGraph {
+        let nodes = rotations
+            .into_iter()
+            .enumerate()
+            .map(|(v, rotation)| {
+                rotation
+                    .into_iter()
+                    .map(|edge| {
+                        let (from, to) = edges[edge];
+                        (if from == v { to } else { from }, edge, true)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        ListGraph {
+            nodes,
+            edges: edges
+                .into_iter()
+                .map(|(from, to)| (from, to, true))
+                .collect(),
+        }
+    }
+
     fn add_dart(&mut self, from: NodeId, to: NodeId, edge_id: EdgeId, after: Option<EdgeId>) {
         if self.nodes.len() < from + 1 {
             self.nodes.resize_with(from + 1, Vec::new);
@@ -272,6 +380,11 @@ impl ListGraph {
         new_len - 1
     }
 
+    /// Returns the graph as a LinkGraph with the faces given by the rotation system
+    pub fn to_link_graph(&self) -> LinkGraph {
+        LinkGraph::from_list_graph(self)
+    }
+
     /// Returns the graph as a petgraph StableGraph
     pub fn to_pet_graph(&self) -> UndirectedGraph {
         StableGraph::from_edges(
@@ -323,6 +436,7 @@ impl<'a, T, VF: Fn(&T) -> bool> Iterator for IndexIter<'a, T, VF> {
 #[cfg(test)]
 mod tests {
     use super::ListGraph;
+    use crate::data_structure::graph_dcel::GraphDCEL;
 
     const K4_EDGE_LIST: [(usize, usize); 6] = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
 
@@ -598,4 +712,39 @@ mod tests {
         assert_eq!(pet_graph.node_count(), 4);
         assert_eq!(pet_graph.edge_count(), 6);
     }
+
+    #[test]
+    fn test_from_faces() {
+        // a square with a diagonal
+        let graph = ListGraph::from_faces(&[vec![0, 1, 2], vec![0, 2, 3], vec![3, 2, 1, 0]]);
+        let link_graph = graph.to_link_graph();
+
+        link_graph.validate();
+        assert_eq!(graph.edge_indexes().count(), 5);
+        assert_eq!(graph.neighbors(0).unwrap().len(), 3);
+        assert_eq!(link_graph.face_count(), 3);
+
+        let mut face_sizes = link_graph
+            .get_faces()
+            .map(|face| link_graph.face_vertex_count(&face))
+            .collect::<Vec<_>>();
+        face_sizes.sort_unstable();
+        assert_eq!(face_sizes, vec![3, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_faces_invalid() {
+        // both triangles are oriented the same way
+        ListGraph::from_faces(&[vec![0, 1, 2], vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn test_to_link_graph() {
+        let link_graph = ListGraph::k4().to_link_graph();
+        link_graph.validate();
+        assert_eq!(link_graph.vertex_count(), 4);
+        assert_eq!(link_graph.edge_count(), 6);
+        assert_eq!(link_graph.face_count(), 4);
+    }
 }
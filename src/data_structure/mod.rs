@@ -4,9 +4,11 @@
 pub mod dot_reader;
 pub mod dot_renderer;
 pub mod graph_dcel;
+pub mod io;
 #[allow(dead_code)]
 pub mod link_graph;
 #[allow(dead_code)]
 pub mod list_graph;
 #[allow(dead_code)]
 pub mod ring_segment;
+pub mod vec_dcel;
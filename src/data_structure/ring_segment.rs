@@ -190,6 +190,37 @@ impl<
         }
     }
 
+    // The darts next to skipped parts of the input graph all get the outer face while
+    // visiting, but every cycle around a skipped part is a face of its own
+    fn split_outer_face(&mut self) {
+        let outer_face = match &self.outer_face {
+            Some(outer_face) => outer_face.clone(),
+            None => return,
+        };
+        let mut visited = HashSet::new();
+        let outer_dart = self.output_graph.dart_face(&outer_face);
+        let darts = std::iter::once(outer_dart.clone())
+            .chain(self.output_graph.get_darts())
+            .collect::<Vec<_>>();
+
+        for dart in darts {
+            if visited.contains(&dart) || self.output_graph.face(&dart) != outer_face {
+                continue;
+            }
+
+            let face = if dart == outer_dart {
+                outer_face.clone()
+            } else {
+                self.output_graph.add_face(dart.clone())
+            };
+            let mut current = dart;
+            while visited.insert(current.clone()) {
+                self.output_graph.set_face(&current, face.clone());
+                current = self.output_graph.next(&current);
+            }
+        }
+    }
+
     fn get_vertex(&mut self, vertex: V) -> Option<V> {
         let ring_of_vertex = *self.ring_map.get(&vertex).unwrap();
         match ring_of_vertex.cmp(&self.i) {
@@ -253,6 +284,9 @@ enum Mode {
 }
 
 /// Returns a tuple which contains new graph containing the nodes of ring `i` and the nodes less then `i` combined in a single node and the combined node.
+///
+/// The rings depend on the chosen outer face, so they are usually computed by
+/// `Leveling::from_face` rooted at the outer face of the embedding.
 pub fn ring_segment<
     V: Vertex + Eq + Hash + Clone + Debug,
     D: Dart + Eq + Hash + Clone + Debug,
@@ -269,16 +303,21 @@ pub fn ring_segment<
 ) -> (G, Option<V>, Option<V>) {
     let mut state = RingSegmentationState::init(input_graph, start_vertex, rings, i);
     state.dfs();
+    state.split_outer_face();
     let new_start_vertex = state.vertexes.get(&state.start_vertex).cloned();
     (state.output_graph, new_start_vertex, state.inner_vertex)
 }
 
 #[cfg(test)]
 mod test {
+    use crate::algorithm::leveling::Leveling;
+    use crate::algorithm::outerplanarity::min_outerplanarity_face;
     use crate::data_structure::graph_dcel::GraphDCEL;
     use crate::data_structure::link_graph::example::three_ring_graph;
     use crate::data_structure::link_graph::{LinkDart, LinkGraph, LinkVertex};
     use crate::data_structure::ring_segment::ring_segment;
+    use crate::embedding::{index::Embedding, maximal_planar::index::MaximalPlanar};
+    use crate::generation::planar::generate;
     use std::collections::HashSet;
 
     fn get_ring_graph() -> (LinkGraph, LinkVertex, Vec<HashSet<LinkVertex>>) {
@@ -325,6 +364,38 @@ mod test {
         assert_eq!(current_dart, dart);
     }
 
+    #[test]
+    fn test_rings_from_outer_face() {
+        let mut graph = MaximalPlanar::embed(generate(60, Some(3)).to_pet_graph());
+        let face = min_outerplanarity_face(&graph).unwrap();
+        graph.set_outer_face(&face);
+        let rings = Leveling::from_face(&graph, &face).rings(2);
+        let start_vertex = graph.face_vertices(&face).next().unwrap();
+
+        for i in 0..rings.len() {
+            let (segmented, _, inner_vertex) =
+                ring_segment(&graph, start_vertex.clone(), rings.clone(), i);
+            segmented.validate();
+            assert_eq!(inner_vertex.is_some(), i > 0);
+            assert_eq!(
+                segmented.vertex_count(),
+                rings[i].len() + usize::from(i > 0)
+            );
+
+            // every face is a single cycle of darts, also around separate skipped parts
+            let mut visited = HashSet::new();
+            let mut faces = HashSet::new();
+            for dart in segmented.get_darts() {
+                if !visited.contains(&dart) {
+                    assert!(faces.insert(segmented.face(&dart)));
+                }
+                for dart in segmented.face_darts(&segmented.face(&dart)) {
+                    visited.insert(dart);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_ring_one() {
         let (graph, start_vertex, rings) = get_ring_graph();
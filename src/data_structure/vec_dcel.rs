@@ -0,0 +1,857 @@
+//! Contains an index based implementation of the DCEL trait
+//!
+//! Vertices, darts and faces are stored in vectors and referenced by their index,
+//! so the graph is `Send` and `Sync` and can be shared across threads.
+//!
+//! ```rust
+//! use graph_algo_ptas::data_structure::{graph_dcel::GraphDCEL, vec_dcel::VecDcel};
+//! use graph_algo_ptas::embedding::maximal_planar::index::MaximalPlanar;
+//! use graph_algo_ptas::generation::planar::generate;
+//!
+//! let dcel: VecDcel = MaximalPlanar::embed_into(generate(10, Some(1)).to_pet_graph());
+//! assert_eq!(dcel.face_count(), dcel.edge_count() + 2 - dcel.vertex_count());
+//! ```
+
+use super::graph_dcel::{dart_removal, Dart, Face, GraphDCEL, Vertex};
+use std::collections::{HashMap, HashSet};
+
+/// A vertex in the VecDcel
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VecVertex(usize);
+
+impl Vertex for VecVertex {
+    fn get_id(&self) -> usize {
+        self.0
+    }
+}
+
+/// A dart in the VecDcel
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VecDart(usize);
+
+impl Dart for VecDart {}
+
+impl VecDart {
+    /// Returns the id of this VecDart
+    pub fn get_id(&self) -> usize {
+        self.0
+    }
+}
+
+/// A face in the VecDcel
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VecFace(usize);
+
+impl Face for VecFace {}
+
+impl VecFace {
+    /// Returns the id of this VecFace
+    pub fn get_id(&self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct VertexData {
+    dart: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct DartData {
+    target: usize,
+    twin: Option<usize>,
+    next: Option<usize>,
+    prev: Option<usize>,
+    face: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct FaceData {
+    dart: usize,
+    // number of darts referencing the face, the face is removed when it drops to zero
+    darts: usize,
+}
+
+/// An index based implementation of the DCEL trait
+#[derive(Clone, Debug)]
+pub struct VecDcel<N = (), E = (), F = ()> {
+    vertexes: Vec<Option<VertexData>>,
+    darts: Vec<Option<DartData>>,
+    faces: Vec<FaceData>,
+    vertex_count: usize,
+    dart_count: usize,
+    face_count: usize,
+    vertex_weights: HashMap<usize, N>,
+    dart_weights: HashMap<usize, E>,
+    face_weights: HashMap<usize, F>,
+}
+
+impl<N, E, F> Default for VecDcel<N, E, F> {
+    fn default() -> Self {
+        VecDcel {
+            vertexes: vec![],
+            darts: vec![],
+            faces: vec![],
+            vertex_count: 0,
+            dart_count: 0,
+            face_count: 0,
+            vertex_weights: HashMap::new(),
+            dart_weights: HashMap::new(),
+            face_weights: HashMap::new(),
+        }
+    }
+}
+
+impl VecDcel {
+    /// Returns a new empty VecDcel
+    pub fn new() -> VecDcel {
+        Default::default()
+    }
+}
+
+impl<N, E, F> VecDcel<N, E, F> {
+    fn dart_data(&self, dart: usize) -> &DartData {
+        self.darts[dart].as_ref().expect("dart removed")
+    }
+
+    fn dart_data_mut(&mut self, dart: usize) -> &mut DartData {
+        self.darts[dart].as_mut().expect("dart removed")
+    }
+
+    fn assign_face(&mut self, dart: usize, face: Option<usize>) {
+        let old_face = std::mem::replace(&mut self.dart_data_mut(dart).face, face);
+
+        if let Some(old_face) = old_face {
+            self.faces[old_face].darts -= 1;
+
+            if self.faces[old_face].darts == 0 {
+                self.face_count -= 1;
+            }
+        }
+
+        if let Some(face) = face {
+            if self.faces[face].darts == 0 {
+                self.face_count += 1;
+            }
+
+            self.faces[face].darts += 1;
+        }
+    }
+
+    fn source(&self, dart: usize) -> Option<usize> {
+        self.dart_data(dart)
+            .twin
+            .map(|twin| self.dart_data(twin).target)
+    }
+
+    // assigns a single face to all darts of the cycle containing `dart` and returns the cycle
+    fn assign_cycle(&mut self, dart: usize, face: usize) -> Vec<usize> {
+        let mut cycle = vec![dart];
+        let mut current = self.dart_data(dart).next.unwrap();
+
+        while current != dart {
+            cycle.push(current);
+            current = self.dart_data(current).next.unwrap();
+        }
+
+        for d in &cycle {
+            self.assign_face(*d, Some(face));
+        }
+
+        self.faces[face].dart = dart;
+        cycle
+    }
+
+    // adds a dart without any links
+    fn new_dart(&mut self, target: usize, twin: Option<usize>) -> usize {
+        self.darts.push(Some(DartData {
+            target,
+            twin: None,
+            next: None,
+            prev: None,
+            face: None,
+        }));
+        self.dart_count += 1;
+        let id = self.darts.len() - 1;
+
+        if let Some(twin) = twin {
+            self.set_twins(id, twin);
+        }
+
+        id
+    }
+
+    fn link(&mut self, prev: usize, next: usize) {
+        self.dart_data_mut(prev).next = Some(next);
+        self.dart_data_mut(next).prev = Some(prev);
+    }
+
+    fn set_twins(&mut self, dart: usize, twin: usize) {
+        self.dart_data_mut(dart).twin = Some(twin);
+        self.dart_data_mut(twin).twin = Some(dart);
+    }
+
+    // returns the darts starting at the source of the given dart in cyclic order
+    fn rotation(&self, first: usize) -> Vec<usize> {
+        let mut rotation = vec![first];
+        let mut current = self.next(&self.twin(&VecDart(first))).0;
+
+        while current != first {
+            rotation.push(current);
+            current = self.next(&self.twin(&VecDart(current))).0;
+        }
+
+        rotation
+    }
+
+    // removes the given darts, which have to contain the twin of every dart, and reassigns
+    // the faces of the remaining darts of the affected cycles. If `contract` is set, the
+    // removed edges are contracted instead of deleted.
+    fn remove_darts(&mut self, darts: Vec<VecDart>, contract: bool) {
+        let removal = dart_removal(self, &darts, contract);
+
+        for (vertex, dart) in removal.vertex_darts {
+            self.vertexes[vertex.0].as_mut().unwrap().dart = dart.map(|dart| dart.0);
+        }
+
+        for (prev, next) in &removal.links {
+            self.link(prev.0, next.0);
+        }
+
+        for dart in &darts {
+            self.assign_face(dart.0, None);
+            self.darts[dart.0] = None;
+            self.dart_weights.remove(&dart.0);
+            self.dart_count -= 1;
+        }
+
+        let mut faces = removal.faces;
+        faces.reverse();
+        let mut visited = HashSet::new();
+
+        for (start, _) in removal.links {
+            if visited.contains(&start.0) {
+                continue;
+            }
+
+            let face = match faces.pop() {
+                Some(face) => face.0,
+                None => {
+                    self.faces.push(FaceData {
+                        dart: start.0,
+                        darts: 0,
+                    });
+                    self.faces.len() - 1
+                }
+            };
+
+            visited.extend(self.assign_cycle(start.0, face));
+        }
+
+        for face in faces {
+            self.face_weights.remove(&face.0);
+        }
+    }
+
+    /// Validates the integrity of the graph. Panics if graph is invalid.
+    pub fn validate(&self) {
+        for (id, dart) in self.darts.iter().enumerate() {
+            let dart = match dart {
+                Some(dart) => dart,
+                None => continue,
+            };
+            let next = dart.next.expect("next required");
+            let prev = dart.prev.expect("prev required");
+            let twin = dart.twin.expect("twin required");
+            let face = dart.face.expect("face required");
+
+            assert_eq!(self.dart_data(next).prev, Some(id), "next and prev differ");
+            assert_eq!(self.dart_data(prev).next, Some(id), "prev and next differ");
+            assert_eq!(self.dart_data(twin).twin, Some(id), "twin non symmetric");
+            assert_eq!(self.source(next), Some(dart.target), "next not adjacent");
+            assert_eq!(
+                self.dart_data(next).face,
+                Some(face),
+                "face differs in cycle"
+            );
+        }
+
+        for (id, vertex) in self.vertexes.iter().enumerate() {
+            if let Some(dart) = vertex.as_ref().and_then(|vertex| vertex.dart) {
+                assert_eq!(self.source(dart), Some(id), "dart not starting at vertex");
+            }
+        }
+
+        for face in self.faces.iter().filter(|face| face.darts > 0) {
+            assert!(self.darts[face.dart].is_some(), "dart of face removed");
+        }
+    }
+}
+
+impl<N, E, F>
+    GraphDCEL<
+        VecVertex,
+        VecDart,
+        VecFace,
+        std::vec::IntoIter<VecVertex>,
+        std::vec::IntoIter<VecDart>,
+        std::vec::IntoIter<VecFace>,
+    > for VecDcel<N, E, F>
+{
+    type VertexWeight = N;
+    type DartWeight = E;
+    type FaceWeight = F;
+
+    fn get_vertexes(&self) -> std::vec::IntoIter<VecVertex> {
+        (0..self.vertexes.len())
+            .filter(|v| self.vertexes[*v].is_some())
+            .map(VecVertex)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn get_darts(&self) -> std::vec::IntoIter<VecDart> {
+        (0..self.darts.len())
+            .filter(|d| self.darts[*d].is_some())
+            .map(VecDart)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn get_faces(&self) -> std::vec::IntoIter<VecFace> {
+        (0..self.faces.len())
+            .filter(|f| self.faces[*f].darts > 0)
+            .map(VecFace)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn vertex_count(&self) -> usize {
+        self.vertex_count
+    }
+
+    fn dart_count(&self) -> usize {
+        self.dart_count
+    }
+
+    fn edge_count(&self) -> usize {
+        self.dart_count / 2
+    }
+
+    fn face_count(&self) -> usize {
+        self.face_count
+    }
+
+    fn face_vertex_count(&self, face: &VecFace) -> usize {
+        self.face_darts(face).count()
+    }
+
+    fn neighbors_count(&self, vertex: &VecVertex) -> usize {
+        self.neighbors(vertex).len()
+    }
+
+    fn neighbors(&self, vertex: &VecVertex) -> Vec<VecVertex> {
+        // a vertex without edges has no rotation
+        if self.vertexes[vertex.0]
+            .as_ref()
+            .and_then(|v| v.dart)
+            .is_none()
+        {
+            return vec![];
+        }
+
+        self.vertex_rotation(vertex)
+            .map(|dart| self.dart_target(&dart))
+            .collect()
+    }
+
+    fn vertex_by_id(&self, id: usize) -> Option<VecVertex> {
+        self.vertexes
+            .get(id)
+            .and_then(|vertex| vertex.as_ref())
+            .map(|_| VecVertex(id))
+    }
+
+    fn get_dart(&self, vertex: &VecVertex, target: &VecVertex) -> Option<VecDart> {
+        self.vertexes[vertex.0].as_ref()?.dart?;
+        self.vertex_rotation(vertex)
+            .find(|dart| self.dart_target(dart) == *target)
+    }
+
+    fn dart_vertex(&self, vertex: &VecVertex) -> VecDart {
+        VecDart(self.vertexes[vertex.0].as_ref().unwrap().dart.unwrap())
+    }
+
+    fn dart_face(&self, face: &VecFace) -> VecDart {
+        VecDart(self.faces[face.0].dart)
+    }
+
+    fn twin(&self, dart: &VecDart) -> VecDart {
+        VecDart(self.dart_data(dart.0).twin.unwrap())
+    }
+
+    fn dart_target(&self, dart: &VecDart) -> VecVertex {
+        VecVertex(self.dart_data(dart.0).target)
+    }
+
+    fn face(&self, dart: &VecDart) -> VecFace {
+        VecFace(self.dart_data(dart.0).face.unwrap())
+    }
+
+    fn next(&self, current: &VecDart) -> VecDart {
+        VecDart(self.dart_data(current.0).next.unwrap())
+    }
+
+    fn prev(&self, current: &VecDart) -> VecDart {
+        VecDart(self.dart_data(current.0).prev.unwrap())
+    }
+
+    fn add_vertex(&mut self) -> VecVertex {
+        self.vertexes.push(Some(VertexData::default()));
+        self.vertex_count += 1;
+        VecVertex(self.vertexes.len() - 1)
+    }
+
+    fn add_dart(
+        &mut self,
+        from: VecVertex,
+        to: VecVertex,
+        prev: Option<VecDart>,
+        next: Option<VecDart>,
+        twin: Option<VecDart>,
+        face: Option<VecFace>,
+    ) -> VecDart {
+        let id = self.darts.len();
+        self.darts.push(Some(DartData {
+            target: to.0,
+            twin: None,
+            next: None,
+            prev: None,
+            face: None,
+        }));
+        self.dart_count += 1;
+
+        let prev_dart = match prev {
+            Some(prev_dart) => prev_dart.0,
+            None => next
+                .and_then(|next| self.dart_data(next.0).prev)
+                .unwrap_or(id),
+        };
+        let next_dart = match next {
+            Some(next_dart) => next_dart.0,
+            None => self.dart_data(prev_dart).next.unwrap_or(id),
+        };
+
+        self.dart_data_mut(next_dart).prev = Some(id);
+        self.dart_data_mut(id).next = Some(next_dart);
+        self.dart_data_mut(prev_dart).next = Some(id);
+        self.dart_data_mut(id).prev = Some(prev_dart);
+
+        if let Some(twin) = twin {
+            self.dart_data_mut(twin.0).twin = Some(id);
+            self.dart_data_mut(id).twin = Some(twin.0);
+        }
+
+        self.assign_face(id, face.map(|face| face.0));
+        self.vertexes[from.0].as_mut().unwrap().dart = Some(id);
+
+        VecDart(id)
+    }
+
+    fn add_face(&mut self, dart: VecDart) -> VecFace {
+        self.faces.push(FaceData {
+            dart: dart.0,
+            darts: 0,
+        });
+        self.assign_face(dart.0, Some(self.faces.len() - 1));
+
+        VecFace(self.faces.len() - 1)
+    }
+
+    fn set_face(&mut self, dart: &VecDart, face: VecFace) {
+        self.assign_face(dart.0, Some(face.0));
+    }
+
+    fn change_face(
+        &mut self,
+        dart: &VecDart,
+        face: Option<VecFace>,
+        prev: Option<VecDart>,
+        next: Option<VecDart>,
+    ) {
+        self.assign_face(dart.0, face.map(|face| face.0));
+
+        let data = self.dart_data_mut(dart.0);
+        data.prev = prev.map(|prev| prev.0);
+        data.next = next.map(|next| next.0);
+    }
+
+    fn remove_edge(&mut self, _from: &VecVertex, dart: VecDart) -> (VecDart, VecDart) {
+        let twin = self.twin(&dart);
+
+        self.remove_darts(vec![dart, twin], false);
+        (dart, twin)
+    }
+
+    fn split_face(&mut self, a: &VecDart, b: &VecDart) -> (VecDart, VecDart) {
+        assert!(
+            a != b && self.face(a) == self.face(b),
+            "darts have to be different darts of the same face"
+        );
+        let (from, to) = (self.dart_data(a.0).target, self.dart_data(b.0).target);
+        assert!(from != to, "loops are not supported");
+        let (a_next, b_next) = (self.next(a).0, self.next(b).0);
+        let face = self.face(a).0;
+        let dart = self.new_dart(to, None);
+        let twin = self.new_dart(from, Some(dart));
+
+        self.link(a.0, dart);
+        self.link(dart, b_next);
+        self.link(b.0, twin);
+        self.link(twin, a_next);
+
+        // the face keeps the cycle containing its dart, the other cycle gets a new face
+        let face_dart = self.faces[face].dart;
+        let cycle = self.assign_cycle(dart, face);
+        self.assign_cycle(twin, face);
+        self.faces[face].dart = face_dart;
+        let start = if cycle.contains(&face_dart) {
+            twin
+        } else {
+            dart
+        };
+        self.faces.push(FaceData {
+            dart: start,
+            darts: 0,
+        });
+        self.assign_cycle(start, self.faces.len() - 1);
+
+        (VecDart(dart), VecDart(twin))
+    }
+
+    fn merge_faces(&mut self, dart: &VecDart) -> VecFace {
+        let twin = self.twin(dart);
+        assert!(
+            self.face(dart) != self.face(&twin),
+            "the dart has to separate two faces"
+        );
+        let prev = self.prev(dart);
+
+        self.remove_darts(vec![*dart, twin], false);
+        self.face(&prev)
+    }
+
+    fn subdivide_edge(&mut self, dart: &VecDart) -> VecVertex {
+        let twin = self.twin(dart).0;
+        let (from, to) = (self.dart_data(twin).target, self.dart_data(dart.0).target);
+        let (dart_next, twin_next) = (self.next(dart).0, self.next(&VecDart(twin)).0);
+        let vertex = self.add_vertex();
+        let dart_end = self.new_dart(to, None);
+        let twin_end = self.new_dart(from, None);
+
+        self.dart_data_mut(dart.0).target = vertex.0;
+        self.dart_data_mut(twin).target = vertex.0;
+        self.set_twins(dart.0, twin_end);
+        self.set_twins(twin, dart_end);
+        self.assign_face(dart_end, self.dart_data(dart.0).face);
+        self.assign_face(twin_end, self.dart_data(twin).face);
+        self.link(dart.0, dart_end);
+        self.link(dart_end, dart_next);
+        self.link(twin, twin_end);
+        self.link(twin_end, twin_next);
+        self.vertexes[vertex.0].as_mut().unwrap().dart = Some(dart_end);
+
+        vertex
+    }
+
+    fn contract_edge(&mut self, dart: &VecDart) -> VecVertex {
+        let twin = self.twin(dart);
+        let vertex = self.dart_data(twin.0).target;
+        let removed = self.dart_data(dart.0).target;
+        assert!(vertex != removed, "loops can not be contracted");
+
+        self.remove_darts(vec![*dart, twin], true);
+
+        if let Some(first) = self.vertexes[removed].take().unwrap().dart {
+            for d in self.rotation(first) {
+                let twin = self.twin(&VecDart(d)).0;
+                self.dart_data_mut(twin).target = vertex;
+            }
+
+            let vertex_data = self.vertexes[vertex].as_mut().unwrap();
+            vertex_data.dart = vertex_data.dart.or(Some(first));
+        }
+
+        self.vertex_count -= 1;
+        self.vertex_weights.remove(&removed);
+
+        if let Some(first) = self.vertexes[vertex].as_ref().unwrap().dart {
+            let mut neighbors = HashSet::new();
+
+            for d in self.rotation(first) {
+                if !neighbors.insert(self.dart_data(d).target) {
+                    self.merge_faces(&VecDart(d));
+                }
+            }
+        }
+
+        VecVertex(vertex)
+    }
+
+    fn remove_vertex(&mut self, vertex: &VecVertex) {
+        if let Some(first) = self.vertexes[vertex.0].as_ref().unwrap().dart {
+            let darts = self
+                .rotation(first)
+                .into_iter()
+                .flat_map(|d| [self.twin(&VecDart(d)), VecDart(d)])
+                .collect();
+            self.remove_darts(darts, false);
+        }
+        self.vertexes[vertex.0] = None;
+
+        self.vertex_count -= 1;
+        self.vertex_weights.remove(&vertex.0);
+    }
+
+    fn vertex_weight(&self, vertex: &VecVertex) -> Option<&N> {
+        self.vertex_weights.get(&vertex.0)
+    }
+
+    fn set_vertex_weight(&mut self, vertex: &VecVertex, weight: N) -> Option<N> {
+        self.vertex_weights.insert(vertex.0, weight)
+    }
+
+    fn dart_weight(&self, dart: &VecDart) -> Option<&E> {
+        self.dart_weights.get(&dart.0)
+    }
+
+    fn set_dart_weight(&mut self, dart: &VecDart, weight: E) -> Option<E> {
+        self.dart_weights.insert(dart.0, weight)
+    }
+
+    fn face_weight(&self, face: &VecFace) -> Option<&F> {
+        self.face_weights.get(&face.0)
+    }
+
+    fn set_face_weight(&mut self, face: &VecFace, weight: F) -> Option<F> {
+        self.face_weights.insert(face.0, weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VecDcel;
+    use crate::{
+        algorithm::{separator::planar_separator, tree_decomposition::face_tree_decomposition},
+        data_structure::graph_dcel::GraphDCEL,
+        embedding::{index::Embedding, maximal_planar::index::MaximalPlanar},
+        generation::planar::generate,
+    };
+    use arboretum_td::graph::{HashMapGraph, MutableGraph};
+    use std::collections::HashSet;
+
+    fn example_graph<N, E, F>() -> VecDcel<N, E, F> {
+        let mut g = VecDcel::default();
+        let v1 = g.add_vertex();
+        let v2 = g.add_vertex();
+        let v3 = g.add_vertex();
+        let d1 = g.add_dart(v1, v2, None, None, None, None);
+        let f = g.add_face(d1);
+        let d2 = g.add_dart(v2, v3, Some(d1), None, None, Some(f));
+        let d3 = g.add_dart(v3, v1, Some(d2), Some(d1), None, Some(f));
+        let t1 = g.add_dart(v2, v1, None, None, Some(d1), None);
+        let of = g.add_face(t1);
+        let t2 = g.add_dart(v3, v2, None, Some(t1), Some(d2), Some(of));
+        g.add_dart(v1, v3, Some(t1), Some(t2), Some(d3), Some(of));
+        g.validate();
+        g
+    }
+
+    #[test]
+    fn counts() {
+        let g: VecDcel = example_graph();
+
+        assert_eq!(g.vertex_count(), 3);
+        assert_eq!(g.dart_count(), 6);
+        assert_eq!(g.edge_count(), 3);
+        assert_eq!(g.face_count(), 2);
+        assert_eq!(g.get_faces().count(), 2);
+        assert_eq!(g.face_vertex_count(&g.get_faces().next().unwrap()), 3);
+        assert_eq!(g.neighbors_count(&g.get_vertexes().next().unwrap()), 2);
+    }
+
+    #[test]
+    fn remove_edge() {
+        let mut g: VecDcel = example_graph();
+        let v = g.get_vertexes().next().unwrap();
+        let dart = g.dart_vertex(&v);
+        let target = g.dart_target(&dart);
+
+        g.remove_edge(&v, dart);
+        g.validate();
+
+        assert!(!g.neighbors(&v).contains(&target));
+        assert_eq!(g.edge_count(), 2);
+        assert_eq!(g.face_count(), 1);
+
+        let dart = g.dart_vertex(&v);
+        g.remove_edge(&v, dart);
+        g.validate();
+
+        assert_eq!(g.edge_count(), 1);
+        assert_eq!(g.face_count(), 1);
+        assert!(g.neighbors(&v).is_empty());
+    }
+
+    fn check_euler(g: &VecDcel) {
+        g.validate();
+        let faces = g.face_count();
+        assert_eq!(faces + g.vertex_count(), g.edge_count() + 2);
+        assert_eq!(g.get_faces().count(), faces);
+
+        for v in g.get_vertexes() {
+            let neighbors = g.neighbors(&v);
+            assert_eq!(
+                neighbors.iter().collect::<HashSet<_>>().len(),
+                neighbors.len()
+            );
+        }
+    }
+
+    #[test]
+    fn mutable_operations() {
+        let mut g: VecDcel = MaximalPlanar::embed_into(generate(30, Some(4)).to_pet_graph());
+        let mut vertex = None;
+
+        for i in 0..5 {
+            let (v, e) = (g.vertex_count(), g.edge_count());
+            let dart = g.get_darts().nth(7 * i).unwrap();
+            let new = g.subdivide_edge(&dart);
+            check_euler(&g);
+            assert_eq!(g.neighbors_count(&new), 2);
+            assert_eq!((g.vertex_count(), g.edge_count()), (v + 1, e + 1));
+            vertex = Some(new);
+        }
+
+        let e = g.edge_count();
+        let a = g.dart_vertex(&vertex.unwrap());
+        let b = g.next(&g.next(&a));
+        let (dart, twin) = g.split_face(&a, &b);
+        check_euler(&g);
+        assert_eq!(g.edge_count(), e + 1);
+        assert_eq!(g.next(&a), dart);
+        assert_eq!(g.next(&b), twin);
+        assert_ne!(g.face(&dart), g.face(&twin));
+
+        let face = g.merge_faces(&dart);
+        check_euler(&g);
+        assert_eq!(g.edge_count(), e);
+        assert_eq!(g.face(&a), face);
+        assert_eq!(g.face(&b), face);
+
+        let v = g.vertex_count();
+        let removed = g.get_vertexes().nth(3).unwrap();
+        g.remove_vertex(&removed);
+        check_euler(&g);
+        assert_eq!(g.vertex_count(), v - 1);
+        assert!(g
+            .get_vertexes()
+            .all(|u| !g.neighbors(&u).contains(&removed)));
+
+        for i in 0..g.vertex_count() - 3 {
+            let v = g.vertex_count();
+            let dart = g.get_darts().nth(11 * i % g.dart_count()).unwrap();
+            let removed = g.dart_target(&dart);
+            let vertex = g.contract_edge(&dart);
+            check_euler(&g);
+            assert_eq!(g.vertex_count(), v - 1);
+            assert!(g.get_vertexes().all(|u| u != removed));
+            assert!(!g.neighbors(&vertex).contains(&removed));
+        }
+    }
+
+    #[test]
+    fn contract_triangle() {
+        let mut g: VecDcel = example_graph();
+        let dart = g.get_darts().next().unwrap();
+
+        g.contract_edge(&dart);
+        g.validate();
+        assert_eq!(g.vertex_count(), 2);
+        assert_eq!(g.edge_count(), 1);
+        assert_eq!(g.face_count(), 1);
+    }
+
+    #[test]
+    fn weights() {
+        let mut g: VecDcel<&str, usize, bool> = example_graph();
+        let v1 = g.vertex_by_id(0).unwrap();
+        let v2 = g.vertex_by_id(1).unwrap();
+        let dart = g.get_dart(&v1, &v2).unwrap();
+        let face = g.face(&dart);
+        g.set_vertex_weight(&v1, "a");
+        g.set_vertex_weight(&v2, "b");
+        g.set_dart_weight(&dart, 1);
+        g.set_face_weight(&face, true);
+
+        assert_eq!(g.vertex_weight(&v1), Some(&"a"));
+        assert_eq!(g.set_dart_weight(&dart, 2), Some(1));
+        assert_eq!(g.dart_weight(&dart), Some(&2));
+        assert_eq!(g.face_weight(&face), Some(&true));
+
+        g.remove_vertex(&v2);
+        assert_eq!(g.vertex_weight(&v1), Some(&"a"));
+        assert_eq!(g.vertex_weight(&v2), None);
+        assert_eq!(g.dart_weight(&dart), None);
+    }
+
+    #[test]
+    fn embed() {
+        for n in [3, 4, 5, 10, 50, 200] {
+            let graph = generate(n, Some(n as u64)).to_pet_graph();
+            let dcel: VecDcel = MaximalPlanar::embed_into(graph.clone());
+            let lg = MaximalPlanar::embed(graph.clone());
+
+            dcel.validate();
+            assert_eq!(dcel.vertex_count(), graph.node_count());
+            assert_eq!(dcel.edge_count(), graph.edge_count());
+            assert_eq!(
+                dcel.face_count(),
+                dcel.edge_count() + 2 - dcel.vertex_count()
+            );
+
+            let mut degrees: Vec<_> = dcel
+                .get_vertexes()
+                .map(|v| dcel.neighbors_count(&v))
+                .collect();
+            let mut link_degrees: Vec<_> =
+                lg.get_vertexes().map(|v| lg.neighbors_count(&v)).collect();
+            degrees.sort_unstable();
+            link_degrees.sort_unstable();
+            assert_eq!(degrees, link_degrees);
+        }
+    }
+
+    #[test]
+    fn algorithms() {
+        let dcel: VecDcel = MaximalPlanar::embed_into(generate(100, Some(1)).to_pet_graph());
+        let mut graph = HashMapGraph::new();
+
+        for v in dcel.get_vertexes() {
+            for u in dcel.neighbors(&v) {
+                graph.add_edge(v.0, u.0);
+            }
+        }
+
+        let separator = planar_separator(&dcel);
+        assert!(3 * separator.a.len() <= 200 && 3 * separator.b.len() <= 200);
+        assert!(face_tree_decomposition(&dcel).verify(&graph).is_ok());
+    }
+
+    #[test]
+    fn send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<VecDcel>();
+    }
+}
@@ -3,28 +3,65 @@
 use super::phase1::Phase1;
 use super::phase2::Phase2;
 use super::phase3::Phase3;
+use crate::data_structure::graph_dcel::{Dart, Face, GraphDCEL, Vertex};
 use crate::data_structure::link_graph::{LinkDart, LinkFace, LinkGraph, LinkGraphIter, LinkVertex};
 use crate::embedding::index::Embedding;
 use crate::utils::convert::UndirectedGraph;
+use petgraph::graph::NodeIndex;
+use std::collections::HashMap;
 
 /// Contains the implementation of the maximal planar embedding algorithm
 pub struct MaximalPlanar {}
 
-impl
-    Embedding<
-        LinkVertex,
-        LinkDart,
-        LinkFace,
-        LinkGraphIter<LinkVertex>,
-        LinkGraphIter<LinkDart>,
-        LinkGraphIter<LinkFace>,
-        LinkGraph,
-    > for MaximalPlanar
-{
-    fn embed(mut graph: UndirectedGraph) -> LinkGraph {
+impl MaximalPlanar {
+    /// Embeds the given maximal planar graph into any default constructible DCEL.
+    ///
+    /// The vertex ids of the resulting DCEL do not necessarily match the node indices of the graph,
+    /// use `embed_with_mapping` to obtain the vertex of every node.
+    pub fn embed_into<
+        V: Vertex + Eq + Clone,
+        D: Dart + Eq + Clone,
+        F: Face + Clone,
+        VI: Iterator<Item = V>,
+        DI: Iterator<Item = D>,
+        FI: Iterator<Item = F>,
+        G: GraphDCEL<V, D, F, VI, DI, FI> + Default,
+    >(
+        graph: UndirectedGraph,
+    ) -> G {
+        MaximalPlanar::embed_with_mapping(graph).0
+    }
+
+    /// Embeds the given maximal planar graph like `embed_into` and returns the vertex
+    /// of the embedding for every node of the graph.
+    ///
+    /// ```rust
+    /// use graph_algo_ptas::data_structure::{graph_dcel::GraphDCEL, link_graph::LinkGraph};
+    /// use graph_algo_ptas::embedding::maximal_planar::index::MaximalPlanar;
+    /// use graph_algo_ptas::generation::planar::generate;
+    ///
+    /// let graph = generate(10, Some(1)).to_pet_graph();
+    /// let (dcel, vertexes): (LinkGraph, _) = MaximalPlanar::embed_with_mapping(graph.clone());
+    ///
+    /// for edge in graph.edge_indices() {
+    ///     let (a, b) = graph.edge_endpoints(edge).unwrap();
+    ///     assert!(dcel.get_dart(&vertexes[&a], &vertexes[&b]).is_some());
+    /// }
+    /// ```
+    pub fn embed_with_mapping<
+        V: Vertex + Eq + Clone,
+        D: Dart + Eq + Clone,
+        F: Face + Clone,
+        VI: Iterator<Item = V>,
+        DI: Iterator<Item = D>,
+        FI: Iterator<Item = F>,
+        G: GraphDCEL<V, D, F, VI, DI, FI> + Default,
+    >(
+        mut graph: UndirectedGraph,
+    ) -> (G, HashMap<NodeIndex, V>) {
         let graph_copy = graph.clone();
         let mut stack = Vec::new();
-        let mut dcel = LinkGraph::new();
+        let mut dcel = G::default();
         let node_count = graph.node_count();
 
         if node_count < 3 {
@@ -33,27 +70,48 @@ impl
 
         if node_count == 3 {
             Phase2::new(&mut dcel).triangle_embedding();
-            return dcel;
+            let node_id_mapper = graph.node_indices().zip(dcel.get_vertexes()).collect();
+            return (dcel, node_id_mapper);
         }
 
         Phase1::new(&mut graph, &mut stack).execute();
         Phase2::new(&mut dcel).execute();
-        Phase3::new(graph, graph_copy, &mut stack, &mut dcel).execute();
+        let mut phase3 = Phase3::new(graph, graph_copy, &mut stack, &mut dcel);
+        phase3.execute();
+        let node_id_mapper = phase3.into_node_id_mapper();
+
+        (dcel, node_id_mapper)
+    }
+}
 
-        dcel
+impl
+    Embedding<
+        LinkVertex,
+        LinkDart,
+        LinkFace,
+        LinkGraphIter<LinkVertex>,
+        LinkGraphIter<LinkDart>,
+        LinkGraphIter<LinkFace>,
+        LinkGraph,
+    > for MaximalPlanar
+{
+    fn embed(graph: UndirectedGraph) -> LinkGraph {
+        MaximalPlanar::embed_into(graph)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use petgraph::graph::NodeIndex;
     use petgraph::stable_graph::StableGraph;
 
-    use crate::data_structure::graph_dcel::GraphDCEL;
+    use crate::data_structure::{graph_dcel::GraphDCEL, link_graph::LinkGraph};
     use crate::{
         embedding::{index::Embedding, maximal_planar::index::MaximalPlanar},
         generation::planar::generate,
         utils::convert::UndirectedGraph,
     };
+    use std::collections::HashSet;
 
     fn test_embed(graph: UndirectedGraph) {
         let dcel = MaximalPlanar::embed(graph.clone());
@@ -75,6 +133,53 @@ mod tests {
         );
     }
 
+    fn test_mapping(graph: UndirectedGraph) {
+        let (dcel, vertexes): (LinkGraph, _) = MaximalPlanar::embed_with_mapping(graph.clone());
+
+        assert_eq!(vertexes.len(), graph.node_count());
+        assert_eq!(
+            vertexes.values().collect::<HashSet<_>>().len(),
+            graph.node_count()
+        );
+
+        for edge in graph.edge_indices() {
+            let (a, b) = graph.edge_endpoints(edge).unwrap();
+            assert!(dcel.get_dart(&vertexes[&a], &vertexes[&b]).is_some());
+        }
+    }
+
+    #[test]
+    fn mapping() {
+        for n in [3, 4, 5, 10, 100] {
+            test_mapping(generate(n, Some(n as u64)).to_pet_graph());
+        }
+    }
+
+    #[test]
+    fn mapping_with_removed_nodes() {
+        let generated = generate(20, Some(1)).to_pet_graph();
+        let mut graph = UndirectedGraph::default();
+
+        for _ in 0..generated.node_count() + 5 {
+            graph.add_node(());
+        }
+
+        for edge in generated.edge_indices() {
+            let (a, b) = generated.edge_endpoints(edge).unwrap();
+            graph.add_edge(
+                NodeIndex::new(a.index() + 5),
+                NodeIndex::new(b.index() + 5),
+                (),
+            );
+        }
+
+        for i in 0..5 {
+            graph.remove_node(NodeIndex::new(i));
+        }
+
+        test_mapping(graph);
+    }
+
     #[test]
     #[should_panic]
     fn embedd_to_small() {
@@ -1,22 +1,37 @@
 //! Implements the second phase of the algorithm.
 //! Here, a planar embedding is created for the K-4 graph left over from the first phase.
 
-use crate::data_structure::link_graph::{LinkDart, LinkGraph, LinkVertex};
+use crate::data_structure::graph_dcel::{Dart, Face, GraphDCEL, Vertex};
+use std::marker::PhantomData;
 
-pub struct Phase2<'a> {
-    dcel: &'a mut LinkGraph,
+pub struct Phase2<'a, V, D, F, VI, DI, FI, G> {
+    dcel: &'a mut G,
+    // PhantomData to make the compiler happy
+    _phantom: PhantomData<(V, D, F, VI, DI, FI)>,
 }
 
-impl Phase2<'_> {
-    pub fn new(dcel: &mut LinkGraph) -> Phase2 {
-        Phase2 { dcel }
+impl<
+        V: Vertex + Eq + Clone,
+        D: Dart + Eq + Clone,
+        F: Face + Clone,
+        VI: Iterator<Item = V>,
+        DI: Iterator<Item = D>,
+        FI: Iterator<Item = F>,
+        G: GraphDCEL<V, D, F, VI, DI, FI>,
+    > Phase2<'_, V, D, F, VI, DI, FI, G>
+{
+    pub fn new(dcel: &mut G) -> Phase2<'_, V, D, F, VI, DI, FI, G> {
+        Phase2 {
+            dcel,
+            _phantom: PhantomData,
+        }
     }
 
     pub fn execute(&mut self) {
-        let v0 = self.dcel.new_vertex();
-        let v1 = self.dcel.new_vertex();
-        let v2 = self.dcel.new_vertex();
-        let v3 = self.dcel.new_vertex();
+        let v0 = self.dcel.add_vertex();
+        let v1 = self.dcel.add_vertex();
+        let v2 = self.dcel.add_vertex();
+        let v3 = self.dcel.add_vertex();
 
         // Face 0
         let (d0, d1, d2) = self.create_face(v0.clone(), v1.clone(), v3.clone(), None, None, None);
@@ -33,9 +48,9 @@ impl Phase2<'_> {
     }
 
     pub fn triangle_embedding(&mut self) {
-        let v0 = self.dcel.new_vertex();
-        let v1 = self.dcel.new_vertex();
-        let v2 = self.dcel.new_vertex();
+        let v0 = self.dcel.add_vertex();
+        let v1 = self.dcel.add_vertex();
+        let v2 = self.dcel.add_vertex();
 
         // Face 0
         let (d0, d1, d2) = self.create_face(v0.clone(), v1.clone(), v2.clone(), None, None, None);
@@ -46,23 +61,23 @@ impl Phase2<'_> {
 
     fn create_face(
         &mut self,
-        v0: LinkVertex,
-        v1: LinkVertex,
-        v2: LinkVertex,
-        t1: Option<LinkDart>,
-        t2: Option<LinkDart>,
-        t3: Option<LinkDart>,
-    ) -> (LinkDart, LinkDart, LinkDart) {
+        v0: V,
+        v1: V,
+        v2: V,
+        t1: Option<D>,
+        t2: Option<D>,
+        t3: Option<D>,
+    ) -> (D, D, D) {
         let d0 = self
             .dcel
-            .new_dart(v0.clone(), v1.clone(), None, None, t1, None);
-        let f0 = self.dcel.new_face(d0.clone());
+            .add_dart(v0.clone(), v1.clone(), None, None, t1, None);
+        let f0 = self.dcel.add_face(d0.clone());
         let d1 = self
             .dcel
-            .new_dart(v1, v2.clone(), Some(d0.clone()), None, t2, Some(f0.clone()));
+            .add_dart(v1, v2.clone(), Some(d0.clone()), None, t2, Some(f0.clone()));
         let d2 = self
             .dcel
-            .new_dart(v2, v0, Some(d1.clone()), Some(d0.clone()), t3, Some(f0));
+            .add_dart(v2, v0, Some(d1.clone()), Some(d0.clone()), t3, Some(f0));
 
         (d0, d1, d2)
     }
@@ -1,33 +1,44 @@
 //! Implements the third phase of the algorithm.
 //! In this phase, the reductions made in phase one are undone to obtain a complete planar embedding of the original graph.
 
-use crate::data_structure::graph_dcel::GraphDCEL;
-use crate::data_structure::link_graph::{LinkDart, LinkFace, LinkGraph, LinkVertex};
+use crate::data_structure::graph_dcel::{Dart, Face, GraphDCEL, Vertex};
 use crate::utils::convert::UndirectedGraph;
 use petgraph::graph::NodeIndex;
 use std::collections::HashMap;
+use std::marker::PhantomData;
 
 use super::stack_item::StackItem;
 
-pub struct Phase3<'a> {
+pub struct Phase3<'a, V, D, F, VI, DI, FI, G> {
     graph: UndirectedGraph,
     graph_copy: UndirectedGraph,
     stack: &'a mut Vec<StackItem>,
-    dcel: &'a mut LinkGraph,
-    node_id_mapper: HashMap<NodeIndex, LinkVertex>,
+    dcel: &'a mut G,
+    node_id_mapper: HashMap<NodeIndex, V>,
+    // PhantomData to make the compiler happy
+    _phantom: PhantomData<(D, F, VI, DI, FI)>,
 }
 
-impl Phase3<'_> {
+impl<
+        V: Vertex + Eq + Clone,
+        D: Dart + Eq + Clone,
+        F: Face + Clone,
+        VI: Iterator<Item = V>,
+        DI: Iterator<Item = D>,
+        FI: Iterator<Item = F>,
+        G: GraphDCEL<V, D, F, VI, DI, FI>,
+    > Phase3<'_, V, D, F, VI, DI, FI, G>
+{
     pub fn new<'a>(
         graph: UndirectedGraph,
         graph_copy: UndirectedGraph,
         stack: &'a mut Vec<StackItem>,
-        dcel: &'a mut LinkGraph,
-    ) -> Phase3<'a> {
+        dcel: &'a mut G,
+    ) -> Phase3<'a, V, D, F, VI, DI, FI, G> {
         let node_id_mapper = graph
             .node_indices()
             .zip(dcel.get_vertexes())
-            .collect::<HashMap<NodeIndex, LinkVertex>>();
+            .collect::<HashMap<NodeIndex, V>>();
 
         Phase3 {
             graph,
@@ -35,6 +46,7 @@ impl Phase3<'_> {
             stack,
             dcel,
             node_id_mapper,
+            _phantom: PhantomData,
         }
     }
 
@@ -54,7 +66,7 @@ impl Phase3<'_> {
             let v = self.stack.pop().unwrap().unwrap_node();
             let new = self.get_or_create_vertex(v);
             let hs = self.pop_edges_to_vextex(hc, new.clone());
-            let mut loop_dart: Option<LinkDart> = None;
+            let mut loop_dart: Option<D> = None;
 
             for e in es {
                 let (a_node, b_node) = e;
@@ -69,8 +81,8 @@ impl Phase3<'_> {
 
             let mut dart = loop_dart.unwrap_or_else(|| self.get_dart_in_face(hs.clone()));
             let last_dart = self.dcel.prev(&dart.clone());
-            let mut last_twin: Option<LinkDart> = None;
-            let mut first_twin: Option<LinkDart> = None;
+            let mut last_twin: Option<D> = None;
+            let mut first_twin: Option<D> = None;
 
             while {
                 let target = self.dcel.dart_target(&dart.clone());
@@ -78,7 +90,7 @@ impl Phase3<'_> {
                 let next = self.dcel.next(&dart.clone());
                 let is_last = last_dart == dart;
                 let t1 = if is_last { first_twin.clone() } else { None };
-                dart.change_face(None, None, None);
+                self.dcel.change_face(&dart, None, None, None);
 
                 let (d0, d1, f) =
                     self.create_face(target, new.clone(), source, dart.clone(), t1, last_twin);
@@ -88,7 +100,7 @@ impl Phase3<'_> {
                     first_twin = Some(d1.clone());
                 }
 
-                dart.change_face(Some(f), Some(d1), Some(d0));
+                self.dcel.change_face(&dart, Some(f), Some(d1), Some(d0));
                 dart = next;
 
                 !is_last
@@ -96,35 +108,35 @@ impl Phase3<'_> {
         }
     }
 
+    /// Returns the vertex of the embedding for every node of the input graph
+    pub fn into_node_id_mapper(self) -> HashMap<NodeIndex, V> {
+        self.node_id_mapper
+    }
+
     fn pop_edges(&mut self, count: i32) -> Vec<(NodeIndex, NodeIndex)> {
         (0..count)
             .map(|_| self.stack.pop().unwrap().unwrap_edge())
             .collect::<Vec<_>>()
     }
 
-    fn pop_edges_to_vextex(&mut self, count: i32, new: LinkVertex) -> Vec<LinkVertex> {
+    fn pop_edges_to_vextex(&mut self, count: i32, new: V) -> Vec<V> {
         self.pop_edges(count)
             .iter()
-            .map(|e| -> LinkVertex {
+            .map(|e| -> V {
                 let (a_node, b_node) = e;
                 self.get_outer_vertex(*a_node, *b_node, new.clone())
             })
-            .collect::<Vec<LinkVertex>>()
+            .collect::<Vec<V>>()
     }
 
-    fn get_or_create_vertex(&mut self, key: NodeIndex) -> LinkVertex {
+    fn get_or_create_vertex(&mut self, key: NodeIndex) -> V {
         self.node_id_mapper
             .entry(key)
-            .or_insert_with(|| self.dcel.new_vertex())
+            .or_insert_with(|| self.dcel.add_vertex())
             .clone()
     }
 
-    fn get_outer_vertex(
-        &mut self,
-        a_node: NodeIndex,
-        b_node: NodeIndex,
-        new: LinkVertex,
-    ) -> LinkVertex {
+    fn get_outer_vertex(&mut self, a_node: NodeIndex, b_node: NodeIndex, new: V) -> V {
         let a_vertex = self.get_or_create_vertex(a_node);
         let b_vertex = self.get_or_create_vertex(b_node);
 
@@ -135,7 +147,7 @@ impl Phase3<'_> {
         a_vertex
     }
 
-    fn get_dart_in_face(&self, hs: Vec<LinkVertex>) -> LinkDart {
+    fn get_dart_in_face(&self, hs: Vec<V>) -> D {
         let dart = self.dcel.get_dart(&hs[0], &hs[1]).unwrap();
         let target = self.dcel.dart_target(&dart);
         let next_target = self.dcel.dart_target(&self.dcel.next(&dart));
@@ -149,20 +161,20 @@ impl Phase3<'_> {
 
     fn create_face(
         &mut self,
-        v0: LinkVertex,
-        v1: LinkVertex,
-        v2: LinkVertex,
-        d3: LinkDart,
-        t1: Option<LinkDart>,
-        t2: Option<LinkDart>,
-    ) -> (LinkDart, LinkDart, LinkFace) {
+        v0: V,
+        v1: V,
+        v2: V,
+        d3: D,
+        t1: Option<D>,
+        t2: Option<D>,
+    ) -> (D, D, F) {
         let d0 = self
             .dcel
-            .new_dart(v0, v1.clone(), Some(d3.clone()), None, t1, None);
-        let f0 = self.dcel.new_face(d0.clone());
+            .add_dart(v0, v1.clone(), Some(d3.clone()), None, t1, None);
+        let f0 = self.dcel.add_face(d0.clone());
         let d1 = self
             .dcel
-            .new_dart(v1, v2, Some(d0.clone()), Some(d3), t2, Some(f0.clone()));
+            .add_dart(v1, v2, Some(d0.clone()), Some(d3), t2, Some(f0.clone()));
 
         (d0, d1, f0)
     }
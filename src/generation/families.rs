@@ -0,0 +1,336 @@
+//! Contains generators for structured families of planar graphs.
+//!
+//! All graphs are built from their faces, so the rotation system of the returned [ListGraph]
+//! is a valid planar embedding. Use [ListGraph::to_pet_graph] to get an
+//! [UndirectedGraph](crate::data_structure::graph_types::UndirectedGraph).
+//!
+//! ```rust
+//! use graph_algo_ptas::generation::families::{apollonian, grid, wheel};
+//!
+//! let graph = grid(4, 3).to_pet_graph();
+//! assert_eq!(graph.node_count(), 12);
+//! assert_eq!(graph.edge_count(), 17);
+//!
+//! // the wheel with 5 rim nodes and a hub
+//! assert_eq!(wheel(5).to_pet_graph().edge_count(), 10);
+//!
+//! // every face of the triangle is subdivided twice
+//! assert_eq!(apollonian(2).to_pet_graph().node_count(), 7);
+//! ```
+
+use crate::data_structure::list_graph::{ListGraph, NodeId};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Returns the grid with `width` columns and `height` rows.
+/// The node in column `x` and row `y` has the id `y * width + x`.
+pub fn grid(width: usize, height: usize) -> ListGraph {
+    grid_faces(width, height, false)
+}
+
+/// Returns the grid with `width` columns and `height` rows, where every cell is split
+/// into two triangles by its diagonal.
+/// The node in column `x` and row `y` has the id `y * width + x`.
+pub fn triangular_grid(width: usize, height: usize) -> ListGraph {
+    grid_faces(width, height, true)
+}
+
+/// Returns the cylinder made of `height` nested cycles of length `circumference`, where
+/// the i-th node of every cycle is connected to the i-th node of the next one.
+/// The i-th node of cycle `r` has the id `r * circumference + i`.
+pub fn cylinder(circumference: usize, height: usize) -> ListGraph {
+    assert!(circumference >= 3, "a cycle requires at least three nodes");
+    assert!(height > 0, "the cylinder requires at least one cycle");
+
+    let rings = (0..height)
+        .map(|r| (r * circumference..(r + 1) * circumference).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mut faces = vec![rings[0].iter().rev().copied().collect()];
+
+    for pair in rings.windows(2) {
+        let (a, b) = (&pair[0], &pair[1]);
+
+        for i in 0..circumference {
+            let j = (i + 1) % circumference;
+            faces.push(vec![a[i], a[j], b[j], b[i]]);
+        }
+    }
+    faces.push(rings[height - 1].clone());
+
+    ListGraph::from_faces(&faces)
+}
+
+/// Returns the wheel with `rim` nodes on the cycle and the hub with the id `rim`.
+pub fn wheel(rim: usize) -> ListGraph {
+    assert!(rim >= 3, "the rim requires at least three nodes");
+
+    let mut faces = vec![(0..rim).rev().collect()];
+    faces.extend((0..rim).map(|i| vec![i, (i + 1) % rim, rim]));
+
+    ListGraph::from_faces(&faces)
+}
+
+/// Returns the fan with a path of `path` nodes and the apex with the id `path`.
+pub fn fan(path: usize) -> ListGraph {
+    assert!(path >= 2, "the path requires at least two nodes");
+
+    let mut outer_face = vec![0, path];
+    outer_face.extend((1..path).rev());
+
+    let mut faces = vec![outer_face];
+    faces.extend((0..path - 1).map(|i| vec![i, i + 1, path]));
+
+    ListGraph::from_faces(&faces)
+}
+
+/// Returns the maximal planar graph of `k` nested triangles. The i-th node of every triangle
+/// is connected to the i-th and (i+1)-th node of the next one.
+/// The i-th node of triangle `r` has the id `3 * r + i`.
+pub fn nested_triangles(k: usize) -> ListGraph {
+    assert!(k > 0, "at least one triangle is required");
+
+    let mut faces = vec![vec![2, 1, 0]];
+
+    for r in 0..k - 1 {
+        let (a, b) = (3 * r, 3 * (r + 1));
+
+        for i in 0..3 {
+            let j = (i + 1) % 3;
+            faces.push(vec![a + i, a + j, b + j]);
+            faces.push(vec![a + i, b + j, b + i]);
+        }
+    }
+    faces.push(vec![3 * k - 3, 3 * k - 2, 3 * k - 1]);
+
+    ListGraph::from_faces(&faces)
+}
+
+/// Returns the Apollonian network obtained from a triangle by inserting a node into every
+/// inner face `depth` times.
+pub fn apollonian(depth: usize) -> ListGraph {
+    let mut faces = vec![vec![0, 1, 2]];
+    let mut next = 3;
+
+    for _ in 0..depth {
+        faces = faces
+            .into_iter()
+            .flat_map(|face| {
+                let v = next;
+                next += 1;
+                [
+                    vec![face[0], face[1], v],
+                    vec![face[1], face[2], v],
+                    vec![face[2], face[0], v],
+                ]
+            })
+            .collect();
+    }
+    faces.push(vec![2, 1, 0]);
+
+    ListGraph::from_faces(&faces)
+}
+
+/// Returns the Halin graph of the complete tree of the given `depth`, whose inner nodes all
+/// have `degree` neighbors, with a cycle through its leaves.
+/// The root has the id 0 and the remaining nodes are numbered in breadth first order.
+pub fn halin(degree: usize, depth: usize) -> ListGraph {
+    assert!(
+        degree >= 3,
+        "the inner nodes require at least three neighbors"
+    );
+    assert!(depth > 0, "the tree requires at least one leaf");
+
+    // the parents of all nodes, children are numbered in counterclockwise order
+    let mut parents = vec![None];
+    let mut level = vec![0];
+
+    for d in 0..depth {
+        let children = if d == 0 { degree } else { degree - 1 };
+        let mut next_level = vec![];
+
+        for &v in &level {
+            for _ in 0..children {
+                next_level.push(parents.len());
+                parents.push(Some(v));
+            }
+        }
+        level = next_level;
+    }
+
+    let path_to_root = |mut v: NodeId| {
+        let mut path = vec![v];
+        while let Some(parent) = parents[v] {
+            path.push(parent);
+            v = parent;
+        }
+        path
+    };
+
+    let leaves = level;
+    let mut faces = vec![leaves.iter().rev().copied().collect::<Vec<_>>()];
+
+    for i in 0..leaves.len() {
+        let (a, b) = (leaves[i], leaves[(i + 1) % leaves.len()]);
+        let (mut up, mut down) = (path_to_root(b), path_to_root(a));
+
+        // cut both paths at the lowest common ancestor
+        while up.len() > 1 && down.len() > 1 && up[up.len() - 2] == down[down.len() - 2] {
+            up.pop();
+            down.pop();
+        }
+        down.pop();
+
+        let mut face = vec![a];
+        face.extend(up);
+        face.extend(down.into_iter().skip(1).rev());
+        faces.push(face);
+    }
+
+    ListGraph::from_faces(&faces)
+}
+
+/// Returns a random two-terminal series-parallel graph with `n` nodes, starting from the edge
+/// between the terminals 0 and 1. Every other node either subdivides an edge or forms a path
+/// of length two parallel to an edge.
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+pub fn series_parallel(n: usize, seed: Option<u64>) -> ListGraph {
+    assert!(n >= 2, "the terminals require two nodes");
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let mut faces: Vec<Vec<NodeId>> = vec![vec![0, 1]];
+
+    for w in 2..n {
+        let f = rng.gen_range(0..faces.len());
+        let i = rng.gen_range(0..faces[f].len());
+        let (u, v) = (faces[f][i], faces[f][(i + 1) % faces[f].len()]);
+        faces[f].insert(i + 1, w);
+
+        if rng.gen_bool(0.5) {
+            // series: the edge is subdivided in the other face as well
+            let (g, j) = find_dart(&faces, v, u);
+            faces[g].insert(j + 1, w);
+        } else {
+            // parallel: the edge is kept next to the new path
+            faces.push(vec![u, v, w]);
+        }
+    }
+
+    ListGraph::from_faces(&faces)
+}
+
+// Returns the face and the position in it of the dart from `u` to `v`
+fn find_dart(faces: &[Vec<NodeId>], u: NodeId, v: NodeId) -> (usize, usize) {
+    faces
+        .iter()
+        .enumerate()
+        .find_map(|(f, face)| {
+            (0..face.len())
+                .find(|&i| face[i] == u && face[(i + 1) % face.len()] == v)
+                .map(|i| (f, i))
+        })
+        .unwrap()
+}
+
+// Returns the faces of the (triangulated) grid
+fn grid_faces(width: usize, height: usize, triangulated: bool) -> ListGraph {
+    assert!(
+        width >= 2 && height >= 2,
+        "the grid requires two rows and columns"
+    );
+    let id = |x: usize, y: usize| y * width + x;
+
+    // clockwise along the left, top, right and bottom side
+    let mut outer_face = (0..height).map(|y| id(0, y)).collect::<Vec<_>>();
+    outer_face.extend((1..width).map(|x| id(x, height - 1)));
+    outer_face.extend((0..height - 1).rev().map(|y| id(width - 1, y)));
+    outer_face.extend((1..width - 1).rev().map(|x| id(x, 0)));
+
+    let mut faces = vec![outer_face];
+
+    for y in 0..height - 1 {
+        for x in 0..width - 1 {
+            let cell = [id(x, y), id(x + 1, y), id(x + 1, y + 1), id(x, y + 1)];
+
+            if triangulated {
+                faces.push(vec![cell[0], cell[1], cell[2]]);
+                faces.push(vec![cell[0], cell[2], cell[3]]);
+            } else {
+                faces.push(cell.to_vec());
+            }
+        }
+    }
+
+    ListGraph::from_faces(&faces)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        apollonian, cylinder, fan, grid, halin, nested_triangles, series_parallel, triangular_grid,
+        wheel,
+    };
+    use crate::data_structure::{graph_dcel::GraphDCEL, list_graph::ListGraph};
+
+    fn check(graph: ListGraph, nodes: usize, edges: usize) {
+        let link_graph = graph.to_link_graph();
+
+        link_graph.validate();
+        assert_eq!(link_graph.vertex_count(), nodes);
+        assert_eq!(link_graph.edge_count(), edges);
+        assert_eq!(link_graph.face_count(), edges + 2 - nodes);
+    }
+
+    #[test]
+    fn grids() {
+        check(grid(2, 2), 4, 4);
+        check(grid(5, 3), 15, 22);
+        check(triangular_grid(2, 2), 4, 5);
+        check(triangular_grid(5, 3), 15, 30);
+        check(cylinder(3, 1), 3, 3);
+        check(cylinder(6, 4), 24, 42);
+    }
+
+    #[test]
+    fn wheels() {
+        check(wheel(3), 4, 6);
+        check(wheel(8), 9, 16);
+        check(fan(2), 3, 3);
+        check(fan(7), 8, 13);
+    }
+
+    #[test]
+    fn triangulations() {
+        for k in 1..5 {
+            check(nested_triangles(k), 3 * k, 9 * k - 6);
+        }
+        for depth in 0..4 {
+            let n = 3 + (3usize.pow(depth as u32) - 1) / 2;
+            check(apollonian(depth), n, 3 * n - 6);
+        }
+    }
+
+    #[test]
+    fn halin_graphs() {
+        // the complete tree of depth one is a star, closing the leaves gives a wheel
+        check(halin(5, 1), 6, 10);
+        check(halin(3, 2), 10, 15);
+        check(halin(4, 3), 1 + 4 + 12 + 36, 4 + 12 + 36 + 36);
+    }
+
+    #[test]
+    fn series_parallel_graphs() {
+        check(series_parallel(2, Some(0)), 2, 1);
+
+        for seed in 0..10 {
+            let graph = series_parallel(30, Some(seed)).to_pet_graph();
+
+            assert_eq!(graph.node_count(), 30);
+            // every node adds one or two edges
+            assert!(graph.edge_count() >= 29 && graph.edge_count() <= 57);
+            check(series_parallel(30, Some(seed)), 30, graph.edge_count());
+        }
+    }
+}
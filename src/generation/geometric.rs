@@ -0,0 +1,410 @@
+//! Contains algorithms to generate geometric planar graphs on random points in the unit square.
+//!
+//! Every generator returns the graph together with the coordinates of its nodes. The rotation
+//! system of the graph is the straight line embedding given by these coordinates.
+//!
+//! The Delaunay triangulation is computed by the Bowyer-Watson algorithm, which checks every
+//! triangle for every inserted point, and the Gabriel and relative neighborhood graphs test
+//! every Delaunay edge against every point. All generators take O(n²) time.
+//!
+//! ```rust
+//! use graph_algo_ptas::generation::geometric::{delaunay, gabriel, relative_neighborhood};
+//!
+//! let (graph, points) = delaunay(50, Some(1));
+//! assert_eq!(points.len(), 50);
+//! assert!(graph.to_pet_graph().edge_count() <= 3 * 50 - 6);
+//!
+//! // the relative neighborhood graph is a subgraph of the gabriel graph
+//! let (rng, _) = relative_neighborhood(50, Some(1));
+//! let (gg, _) = gabriel(50, Some(1));
+//! assert!(rng.to_pet_graph().edge_count() <= gg.to_pet_graph().edge_count());
+//! ```
+
+use crate::data_structure::list_graph::{ListGraph, NodeId};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{BTreeSet, HashSet};
+
+/// The coordinates of a node
+pub type Point = (f64, f64);
+
+/// Returns the Delaunay triangulation of `n` random points.
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+pub fn delaunay(n: usize, seed: Option<u64>) -> (ListGraph, Vec<Point>) {
+    let points = random_points(n, seed);
+    let edges = delaunay_edges(&points);
+
+    (embed(&points, &edges), points)
+}
+
+/// Returns the Gabriel graph of `n` random points, in which two points are adjacent if no
+/// other point lies in the circle having their connecting line as diameter.
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+pub fn gabriel(n: usize, seed: Option<u64>) -> (ListGraph, Vec<Point>) {
+    let points = random_points(n, seed);
+    let edges = gabriel_edges(&points, f64::INFINITY);
+
+    (embed(&points, &edges), points)
+}
+
+/// Returns the relative neighborhood graph of `n` random points, in which two points are
+/// adjacent if no other point is closer to both of them than they are to each other.
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+pub fn relative_neighborhood(n: usize, seed: Option<u64>) -> (ListGraph, Vec<Point>) {
+    let points = random_points(n, seed);
+    let edges = gabriel_edges(&points, f64::INFINITY)
+        .into_iter()
+        .filter(|&(u, v)| {
+            let d = distance(points[u], points[v]);
+            (0..points.len()).all(|w| {
+                w == u
+                    || w == v
+                    || distance(points[u], points[w]).max(distance(points[v], points[w])) >= d
+            })
+        })
+        .collect::<Vec<_>>();
+
+    (embed(&points, &edges), points)
+}
+
+/// Returns the planar subgraph of the unit disk graph of `n` random points, which contains the
+/// edges of the Gabriel graph not longer than `radius`. The graph may be disconnected.
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+///
+/// The rotation system of a disconnected graph does not determine the faces correctly, so
+/// the graph must not be converted to a DCEL. Use [largest_component] to obtain a connected
+/// graph.
+pub fn unit_disk(n: usize, radius: f64, seed: Option<u64>) -> (ListGraph, Vec<Point>) {
+    let points = random_points(n, seed);
+    let edges = gabriel_edges(&points, radius);
+
+    (embed(&points, &edges), points)
+}
+
+/// Returns the straight line embedding of the given edges, in which the neighbors of every
+/// node are ordered counterclockwise. The edges must not cross.
+///
+/// The faces of the rotation system are only correct if the edges form a connected graph,
+/// see [unit_disk].
+pub fn embed(points: &[Point], edges: &[(NodeId, NodeId)]) -> ListGraph {
+    let mut incident = vec![vec![]; points.len()];
+
+    for (edge, &(u, v)) in edges.iter().enumerate() {
+        incident[u].push((v, edge));
+        incident[v].push((u, edge));
+    }
+
+    let rotations = incident
+        .into_iter()
+        .enumerate()
+        .map(|(u, mut neighbors)| {
+            neighbors.sort_by(|&(v, _), &(w, _)| {
+                angle(points[u], points[v]).total_cmp(&angle(points[u], points[w]))
+            });
+            neighbors.into_iter().map(|(_, edge)| edge).collect()
+        })
+        .collect::<Vec<Vec<_>>>();
+
+    ListGraph::from_edges_node_list(
+        edges.iter(),
+        rotations
+            .iter()
+            .map(|rotation| rotation.as_slice())
+            .collect::<Vec<_>>()
+            .iter(),
+    )
+}
+
+/// Returns the straight line embedding of the largest connected component of the graph
+/// together with the coordinates of its nodes, which are renumbered starting from 0.
+pub fn largest_component(graph: &ListGraph, points: &[Point]) -> (ListGraph, Vec<Point>) {
+    let edges = graph.all_edges();
+    // the representative of the component containing every node
+    let mut roots = (0..points.len()).collect::<Vec<_>>();
+
+    for &(u, v) in &edges {
+        let (u, v) = (root(&mut roots, u), root(&mut roots, v));
+        roots[u] = v;
+    }
+
+    let mut sizes = vec![0; points.len()];
+    for u in 0..points.len() {
+        sizes[root(&mut roots, u)] += 1;
+    }
+    let largest = (0..points.len()).max_by_key(|&u| sizes[u]);
+
+    let mut ids = vec![None; points.len()];
+    let mut component_points = vec![];
+    for u in 0..points.len() {
+        if Some(root(&mut roots, u)) == largest {
+            ids[u] = Some(component_points.len());
+            component_points.push(points[u]);
+        }
+    }
+
+    let component_edges = edges
+        .iter()
+        .filter_map(|&(u, v)| Some((ids[u]?, ids[v]?)))
+        .collect::<Vec<_>>();
+
+    (embed(&component_points, &component_edges), component_points)
+}
+
+// Returns the representative of the component containing the node
+fn root(roots: &mut [NodeId], mut u: NodeId) -> NodeId {
+    while roots[u] != u {
+        roots[u] = roots[roots[u]];
+        u = roots[u];
+    }
+
+    u
+}
+
+fn random_points(n: usize, seed: Option<u64>) -> Vec<Point> {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    (0..n).map(|_| (rng.gen(), rng.gen())).collect()
+}
+
+// Returns the edges of the Delaunay triangulation computed by the Bowyer-Watson algorithm.
+// The triangles outside the convex hull are represented by a node at infinity, they contain
+// a point in their "circumcircle" if it lies on the outer side of their hull edge.
+fn delaunay_edges(points: &[Point]) -> Vec<(NodeId, NodeId)> {
+    if points.len() < 3 {
+        return (0..points.len())
+            .flat_map(|u| (u + 1..points.len()).map(move |v| (u, v)))
+            .collect();
+    }
+
+    let infinity = points.len();
+    let (a, b, c) = if orientation(points[0], points[1], points[2]) > 0.0 {
+        (0, 1, 2)
+    } else {
+        (0, 2, 1)
+    };
+    let mut triangles = vec![
+        [a, b, c],
+        [b, a, infinity],
+        [c, b, infinity],
+        [a, c, infinity],
+    ];
+
+    for p in 3..points.len() {
+        let contains = |&[u, v, w]: &[NodeId; 3]| {
+            if w == infinity {
+                orientation(points[u], points[v], points[p]) > 0.0
+            } else {
+                in_circumcircle(points[u], points[v], points[w], points[p])
+            }
+        };
+        let (bad, good): (Vec<_>, Vec<_>) = triangles.into_iter().partition(contains);
+        let darts = bad
+            .iter()
+            .flat_map(|t| (0..3).map(move |i| (t[i], t[(i + 1) % 3])))
+            .collect::<HashSet<_>>();
+
+        triangles = good;
+        for &(u, v) in &darts {
+            if darts.contains(&(v, u)) {
+                continue;
+            }
+
+            // the node at infinity is kept as the last node of a triangle
+            triangles.push(match (u == infinity, v == infinity) {
+                (true, _) => [v, p, infinity],
+                (_, true) => [p, u, infinity],
+                _ => [u, v, p],
+            });
+        }
+    }
+
+    triangles
+        .iter()
+        .flat_map(|t| (0..3).map(move |i| (t[i], t[(i + 1) % 3])))
+        .filter(|&(u, v)| u < v && v != infinity)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+// Returns the edges of the Delaunay triangulation not longer than `radius`, whose diametral
+// circle contains no other point
+fn gabriel_edges(points: &[Point], radius: f64) -> Vec<(NodeId, NodeId)> {
+    delaunay_edges(points)
+        .into_iter()
+        .filter(|&(u, v)| {
+            let center = (
+                (points[u].0 + points[v].0) / 2.0,
+                (points[u].1 + points[v].1) / 2.0,
+            );
+            let d = distance(points[u], points[v]);
+            d <= radius
+                && (0..points.len())
+                    .all(|w| w == u || w == v || distance(center, points[w]) >= d / 2.0)
+        })
+        .collect()
+}
+
+fn distance(a: Point, b: Point) -> f64 {
+    (a.0 - b.0).hypot(a.1 - b.1)
+}
+
+fn angle(from: Point, to: Point) -> f64 {
+    (to.1 - from.1).atan2(to.0 - from.0)
+}
+
+// Returns a positive value if the points are in counterclockwise order
+fn orientation(a: Point, b: Point, c: Point) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+// Returns whether `p` lies inside the circumcircle of the counterclockwise triangle abc
+fn in_circumcircle(a: Point, b: Point, c: Point, p: Point) -> bool {
+    let (ax, ay) = (a.0 - p.0, a.1 - p.1);
+    let (bx, by) = (b.0 - p.0, b.1 - p.1);
+    let (cx, cy) = (c.0 - p.0, c.1 - p.1);
+
+    (ax * ax + ay * ay) * (bx * cy - cx * by) - (bx * bx + by * by) * (ax * cy - cx * ay)
+        + (cx * cx + cy * cy) * (ax * by - bx * ay)
+        > 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        delaunay, delaunay_edges, distance, gabriel, in_circumcircle, largest_component,
+        orientation, relative_neighborhood, unit_disk,
+    };
+    use crate::data_structure::{
+        graph_dcel::GraphDCEL,
+        list_graph::{ListGraph, NodeId},
+    };
+    use itertools::Itertools;
+    use petgraph::algo::kosaraju_scc;
+    use std::collections::HashSet;
+
+    fn edges(graph: &ListGraph) -> HashSet<(NodeId, NodeId)> {
+        graph
+            .all_edges()
+            .into_iter()
+            .map(|(u, v)| (u.min(v), u.max(v)))
+            .collect()
+    }
+
+    #[test]
+    fn delaunay_triangulation() {
+        for n in [3, 4, 10, 100] {
+            let (graph, _) = delaunay(n, Some(n as u64));
+            let link_graph = graph.to_link_graph();
+            let faces = link_graph
+                .get_faces()
+                .map(|face| link_graph.face_vertex_count(&face))
+                .collect::<Vec<_>>();
+
+            link_graph.validate();
+            assert_eq!(link_graph.vertex_count(), n);
+            assert_eq!(
+                link_graph.face_count(),
+                link_graph.edge_count() + 2 - link_graph.vertex_count()
+            );
+            // all faces but the convex hull are triangles
+            assert!(faces.iter().filter(|&&size| size != 3).count() <= 1);
+        }
+    }
+
+    #[test]
+    fn empty_circumcircles() {
+        for n in [3, 4, 10, 30] {
+            let (graph, points) = delaunay(n, Some(n as u64));
+            // the edges of all triangles whose circumcircle contains no other point
+            let mut expected = HashSet::new();
+
+            for (u, v, w) in (0..n).tuple_combinations() {
+                let (v, w) = if orientation(points[u], points[v], points[w]) > 0.0 {
+                    (v, w)
+                } else {
+                    (w, v)
+                };
+                if points
+                    .iter()
+                    .all(|&p| !in_circumcircle(points[u], points[v], points[w], p))
+                {
+                    for (a, b) in [(u, v), (v, w), (w, u)] {
+                        expected.insert((a.min(b), a.max(b)));
+                    }
+                }
+            }
+
+            assert_eq!(edges(&graph), expected);
+        }
+    }
+
+    #[test]
+    fn small() {
+        assert!(delaunay_edges(&[]).is_empty());
+        assert_eq!(delaunay_edges(&[(0.0, 0.0), (1.0, 0.0)]), vec![(0, 1)]);
+        assert_eq!(
+            delaunay_edges(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.1)]).len(),
+            5
+        );
+    }
+
+    #[test]
+    fn proximity_graphs() {
+        for seed in 0..5 {
+            let delaunay = edges(&delaunay(80, Some(seed)).0);
+            let gabriel = edges(&gabriel(80, Some(seed)).0);
+            let (rng, _) = relative_neighborhood(80, Some(seed));
+
+            assert!(gabriel.is_subset(&delaunay));
+            assert!(edges(&rng).is_subset(&gabriel));
+            // the relative neighborhood graph contains the minimum spanning tree
+            assert_eq!(kosaraju_scc(&rng.to_pet_graph()).len(), 1);
+            rng.to_link_graph().validate();
+        }
+    }
+
+    #[test]
+    fn unit_disk_graph() {
+        let (graph, points) = unit_disk(100, 0.15, Some(3));
+        assert_eq!(graph.node_indexes().count(), 100);
+        assert!(edges(&graph)
+            .iter()
+            .all(|&(u, v)| distance(points[u], points[v]) <= 0.15));
+        assert!(edges(&graph).is_subset(&edges(&gabriel(100, Some(3)).0)));
+    }
+
+    #[test]
+    fn unit_disk_largest_component() {
+        for seed in 0..5 {
+            let (graph, points) = unit_disk(100, 0.15, Some(seed));
+            let (component, component_points) = largest_component(&graph, &points);
+            let link_graph = component.to_link_graph();
+            let n = component_points.len();
+
+            link_graph.validate();
+            assert!(n > 1);
+            assert_eq!(kosaraju_scc(&component.to_pet_graph()).len(), 1);
+            assert_eq!(link_graph.vertex_count(), n);
+            assert_eq!(
+                link_graph.face_count(),
+                link_graph.edge_count() + 2 - link_graph.vertex_count()
+            );
+            assert!(edges(&component)
+                .iter()
+                .all(|&(u, v)| { distance(component_points[u], component_points[v]) <= 0.15 }));
+        }
+    }
+
+    #[test]
+    fn seeded() {
+        let (a, p) = delaunay(60, Some(5));
+        let (b, q) = delaunay(60, Some(5));
+
+        assert_eq!(p, q);
+        assert_eq!(a.all_edges(), b.all_edges());
+    }
+}
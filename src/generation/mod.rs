@@ -3,4 +3,16 @@
 #[allow(dead_code)]
 pub mod erdos_renyi;
 #[allow(dead_code)]
+pub mod families;
+#[allow(dead_code)]
+pub mod geometric;
+#[allow(dead_code)]
+pub mod outerplanar;
+#[allow(dead_code)]
+pub mod partial_k_tree;
+#[allow(dead_code)]
 pub mod planar;
+#[allow(dead_code)]
+pub mod planted;
+#[allow(dead_code)]
+pub mod uniform_planar;
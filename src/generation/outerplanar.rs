@@ -0,0 +1,174 @@
+//! Contains algorithms to generate random outerplanar and k-outerplanar graphs.
+//!
+//! The graphs are built from their faces, so the rotation system of the returned
+//! [ListGraph] is a valid planar embedding whose outer face is bounded by the outermost layer.
+//!
+//! ```rust
+//! use graph_algo_ptas::generation::outerplanar::{generate, generate_k_outerplanar};
+//!
+//! // a maximal outerplanar graph with 10 nodes
+//! let graph = generate(10, Some(1)).to_pet_graph();
+//! assert_eq!(graph.edge_count(), 2 * 10 - 3);
+//!
+//! // a graph with 30 nodes in 3 nested layers
+//! let graph = generate_k_outerplanar(30, 3, Some(1)).to_pet_graph();
+//! assert_eq!(graph.node_count(), 30);
+//! ```
+
+use crate::data_structure::list_graph::{ListGraph, NodeId};
+use rand::rngs::StdRng;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+
+/// Returns a random maximal outerplanar graph with `n` nodes.
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+pub fn generate(n: usize, seed: Option<u64>) -> ListGraph {
+    generate_k_outerplanar(n, 1, seed)
+}
+
+/// Returns a random k-outerplanar graph with `n` nodes.
+///
+/// The nodes are split into `k` nested cycles of at least three nodes, neighboring cycles are
+/// connected by a random triangulation of the ring between them and the innermost cycle is
+/// triangulated randomly. Removing the nodes of the outer face `k` times leaves an empty graph.
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+pub fn generate_k_outerplanar(n: usize, k: usize, seed: Option<u64>) -> ListGraph {
+    assert!(k > 0, "k has to be positive");
+    assert!(n >= 3 * k, "every layer requires at least three nodes");
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let mut sizes = vec![3; k];
+    for _ in 0..n - 3 * k {
+        sizes[rng.gen_range(0..k)] += 1;
+    }
+
+    let mut layers: Vec<Vec<NodeId>> = vec![];
+    for size in sizes {
+        let first = layers.iter().map(|layer| layer.len()).sum::<usize>();
+        layers.push((first..first + size).collect());
+    }
+
+    let mut faces = vec![layers[0].iter().rev().copied().collect()];
+    for pair in layers.windows(2) {
+        faces.extend(zip_layers(&pair[0], &pair[1], true, &mut rng));
+    }
+    triangulate_polygon(&layers[k - 1], &mut rng, &mut faces);
+
+    ListGraph::from_faces(&faces)
+}
+
+/// Returns the triangles between two layers in a random order of advancing along them.
+///
+/// If `closed` is set the layers are cycles in counterclockwise order, the first one enclosing
+/// the second one. Otherwise the layers are paths running downwards, the first one on the left.
+pub(crate) fn zip_layers(
+    a: &[NodeId],
+    b: &[NodeId],
+    closed: bool,
+    rng: &mut StdRng,
+) -> Vec<Vec<NodeId>> {
+    let open = usize::from(!closed);
+    let mut steps = vec![true; a.len() - open];
+    steps.extend(vec![false; b.len() - open]);
+    steps.shuffle(rng);
+
+    let (mut x, mut y) = (0, 0);
+    let mut faces = vec![];
+
+    for step in steps {
+        if step {
+            faces.push(vec![a[x % a.len()], a[(x + 1) % a.len()], b[y % b.len()]]);
+            x += 1;
+        } else {
+            faces.push(vec![a[x % a.len()], b[(y + 1) % b.len()], b[y % b.len()]]);
+            y += 1;
+        }
+    }
+
+    faces
+}
+
+/// Adds the triangles of a random triangulation of the given counterclockwise polygon to `faces`
+pub(crate) fn triangulate_polygon(
+    polygon: &[NodeId],
+    rng: &mut StdRng,
+    faces: &mut Vec<Vec<NodeId>>,
+) {
+    if polygon.len() < 3 {
+        return;
+    }
+
+    let apex = rng.gen_range(1..polygon.len() - 1);
+    faces.push(vec![polygon[0], polygon[apex], polygon[polygon.len() - 1]]);
+    triangulate_polygon(&polygon[..=apex], rng, faces);
+    triangulate_polygon(&polygon[apex..], rng, faces);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, generate_k_outerplanar};
+    use crate::algorithm::outerplanarity::outerplanar_leveling;
+    use crate::data_structure::graph_dcel::GraphDCEL;
+    use std::cmp::Reverse;
+
+    fn check_outerplanarity(n: usize, k: usize, seed: u64) {
+        let graph = generate_k_outerplanar(n, k, Some(seed));
+        let link_graph = graph.to_link_graph();
+        let (v0, v1) = (
+            link_graph.vertex_by_id(0).unwrap(),
+            link_graph.vertex_by_id(1).unwrap(),
+        );
+        let dart = link_graph.get_dart(&v0, &v1).unwrap();
+        // the outer face is the larger face next to the edge between the first two nodes, or
+        // the one containing only nodes of the outer layer if both are triangles
+        let outer_face = [
+            link_graph.face(&dart),
+            link_graph.face(&link_graph.twin(&dart)),
+        ]
+        .into_iter()
+        .min_by_key(|face| {
+            (
+                Reverse(link_graph.face_vertex_count(face)),
+                link_graph.face_vertices(face).map(|v| v.get_id()).max(),
+            )
+        })
+        .unwrap();
+
+        link_graph.validate();
+        assert_eq!(link_graph.vertex_count(), n);
+        assert_eq!(
+            link_graph.face_count(),
+            link_graph.edge_count() + 2 - link_graph.vertex_count()
+        );
+        assert_eq!(outerplanar_leveling(&link_graph, &outer_face).size(), k);
+    }
+
+    #[test]
+    fn outerplanar() {
+        for n in [3, 4, 10, 50] {
+            let graph = generate(n, Some(n as u64)).to_pet_graph();
+
+            assert_eq!(graph.node_count(), n);
+            assert_eq!(graph.edge_count(), 2 * n - 3);
+        }
+    }
+
+    #[test]
+    fn k_outerplanar() {
+        check_outerplanarity(3, 1, 0);
+        check_outerplanarity(20, 1, 1);
+        check_outerplanarity(6, 2, 2);
+        check_outerplanarity(40, 4, 3);
+        check_outerplanarity(100, 7, 4);
+    }
+
+    #[test]
+    fn seeded() {
+        let a = generate_k_outerplanar(50, 3, Some(7));
+        let b = generate_k_outerplanar(50, 3, Some(7));
+
+        assert_eq!(a.all_edges(), b.all_edges());
+    }
+}
@@ -0,0 +1,132 @@
+//! Contains algorithms for generating random partial k-trees together with a tree
+//! decomposition of width at most k.
+//!
+//! ```rust
+//! use graph_algo_ptas::algorithm::dynamic_programming::solve::{dp_solve, DpProblem};
+//! use graph_algo_ptas::generation::partial_k_tree::generate_petgraph;
+//!
+//! let (graph, td) = generate_petgraph(30, 3, 0.5, Some(1));
+//! assert!(td.max_bag_size <= 4);
+//! let sol = dp_solve(&graph, Some(td), &DpProblem::max_independent_set());
+//! ```
+
+use crate::utils::convert::{to_hash_map_graph, UndirectedGraph};
+use arboretum_td::graph::HashMapGraph;
+use arboretum_td::tree_decomposition::TreeDecomposition;
+use petgraph::stable_graph::NodeIndex;
+use rand::{rngs::StdRng, seq::IteratorRandom, Rng, SeedableRng};
+
+/// Generates a random partial k-tree of type [UndirectedGraph] with `n` nodes and a tree
+/// decomposition of width `k` of it.
+///
+/// A random k-tree is built by starting with a clique of `k + 1` nodes and connecting every
+/// further node to a random k-clique. The bag of the new node is attached to the bag
+/// containing the clique. Each edge of the k-tree is kept with probability `p`.
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+pub fn generate_petgraph(
+    n: usize,
+    k: usize,
+    p: f64,
+    seed: Option<u64>,
+) -> (UndirectedGraph, TreeDecomposition) {
+    assert!(n > k, "a k-tree requires at least k + 1 nodes");
+    let mut graph = UndirectedGraph::default();
+    let mut td = TreeDecomposition::default();
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    for _ in 0..n {
+        graph.add_node(());
+    }
+
+    for v in 0..=k {
+        for w in v + 1..=k {
+            if rng.gen_bool(p) {
+                graph.add_edge(NodeIndex::new(v), NodeIndex::new(w), ());
+            }
+        }
+    }
+    td.add_bag((0..=k).collect());
+
+    for v in k + 1..n {
+        let parent = rng.gen_range(0..td.bags().len());
+        let mut bag = td.bags()[parent].vertex_set.clone();
+        let removed = *bag.iter().choose(&mut rng).unwrap();
+        bag.remove(&removed);
+
+        for &w in &bag {
+            if rng.gen_bool(p) {
+                graph.add_edge(NodeIndex::new(v), NodeIndex::new(w), ());
+            }
+        }
+
+        bag.insert(v);
+        let id = td.add_bag(bag);
+        td.add_edge(parent, id);
+    }
+
+    (graph, td)
+}
+
+/// Generates a random partial k-tree of type [HashMapGraph] with `n` nodes and a tree
+/// decomposition of width `k` of it, see [generate_petgraph].
+pub fn generate_hash_map_graph(
+    n: usize,
+    k: usize,
+    p: f64,
+    seed: Option<u64>,
+) -> (HashMapGraph, TreeDecomposition) {
+    let (graph, td) = generate_petgraph(n, k, p, seed);
+    (to_hash_map_graph(&graph), td)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_hash_map_graph, generate_petgraph};
+    use crate::algorithm::dynamic_programming::solve::{dp_solve_hashmap_graph, DpProblem};
+    use crate::utils::max_independent_set::brute_force_max_independent_set;
+    use arboretum_td::graph::BaseGraph;
+
+    #[test]
+    fn k_tree() {
+        for (n, k) in [(1, 0), (5, 1), (10, 2), (30, 4), (50, 7)] {
+            let (graph, td) = generate_hash_map_graph(n, k, 1.0, Some(n as u64));
+
+            assert!(td.verify(&graph).is_ok());
+            assert_eq!(td.max_bag_size, k + 1);
+            assert_eq!(graph.order(), n);
+            assert_eq!(
+                generate_petgraph(n, k, 1.0, Some(n as u64)).0.edge_count(),
+                k * (k + 1) / 2 + (n - k - 1) * k
+            );
+        }
+    }
+
+    #[test]
+    fn partial_k_tree() {
+        for seed in 0..10 {
+            let (graph, td) = generate_hash_map_graph(16, 3, 0.6, Some(seed));
+            let expected = brute_force_max_independent_set(&graph).len();
+            let sol = dp_solve_hashmap_graph(&graph, Some(td), &DpProblem::max_independent_set());
+
+            assert_eq!(sol.len(), expected);
+        }
+    }
+
+    #[test]
+    fn seeded() {
+        let (a, _) = generate_petgraph(40, 5, 0.5, Some(2));
+        let (b, _) = generate_petgraph(40, 5, 0.5, Some(2));
+
+        assert_eq!(
+            a.edge_indices()
+                .map(|e| a.edge_endpoints(e))
+                .collect::<Vec<_>>(),
+            b.edge_indices()
+                .map(|e| b.edge_endpoints(e))
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -13,117 +13,368 @@
 //! assert_eq!(dg.node_count(), 100);
 //! assert_eq!(dg.edge_count(), 3 * 100 - 6);
 //! ```
+//!
+//! The embedding of the generated graphs is kept by generating a [LinkGraph] directly.
+//!
+//! ```rust
+//! use graph_algo_ptas::data_structure::graph_dcel::GraphDCEL;
+//! use graph_algo_ptas::generation::planar::generate_link_graph;
+//!
+//! let graph = generate_link_graph(10, Some(1));
+//! assert_eq!(graph.face_count(), 2 * 10 - 4);
+//! ```
+//!
+//! Sparser graphs are derived from these triangulations, graphs with a given diameter are
+//! built from layers of paths.
+//!
+//! ```rust
+//! use graph_algo_ptas::generation::planar::{generate_with_diameter, generate_with_edges};
+//!
+//! let sparse = generate_with_edges(100, 150, Some(1)).to_pet_graph();
+//! assert_eq!(sparse.edge_count(), 150);
+//! let long = generate_with_diameter(100, 20, Some(1)).to_pet_graph();
+//! assert_eq!(long.node_count(), 100);
+//! ```
 
-use crate::data_structure::list_graph::ListGraph;
+use crate::data_structure::link_graph::LinkGraph;
+use crate::data_structure::list_graph::{ListGraph, NodeId};
+use crate::generation::outerplanar::zip_layers;
 use rand::rngs::StdRng;
 use rand::{seq::SliceRandom, Rng, SeedableRng};
 
 /// Returns a randomly generated Graph with degree `n`.
+/// The graphs are not distributed uniformly, see [crate::generation::uniform_planar] for that.
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+pub fn generate(n: usize, seed: Option<u64>) -> ListGraph {
+    Triangulation::generate(n, seed).to_list_graph()
+}
+
+/// Returns a randomly generated graph like [generate] as [LinkGraph] containing the faces of
+/// its embedding, so it can be used without embedding it again.
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+pub fn generate_link_graph(n: usize, seed: Option<u64>) -> LinkGraph {
+    LinkGraph::from_list_graph(&generate(n, seed))
+}
+
+/// Returns the edges of a graph generated like [generate] without building a [ListGraph].
+///
+/// The generation runs in linear time and stores two 32 bit integers for each of the `6n - 12`
+/// half edges, so it is suitable for graphs with millions of nodes.
 /// If `seed` is not `None` the rng is seeded with the value of `seed`.
-pub fn generate(mut n: usize, seed: Option<u64>) -> ListGraph {
-    #[cfg(feature = "debug_graph_generation")]
-    let mut counter = 0;
-    let max_edges = 5 * n;
-    let mut graph = ListGraph::k4();
-    let mut urn = Vec::with_capacity(max_edges);
-    let mut active = vec![false; max_edges];
+pub fn generate_edges(n: usize, seed: Option<u64>) -> impl Iterator<Item = (NodeId, NodeId)> {
+    let graph = Triangulation::generate(n, seed);
+
+    (0..graph.target.len() / 2).map(move |edge| graph.edge(edge))
+}
+
+// A triangulation stored as half edges, where the half edges 2e and 2e + 1 form the edge e
+struct Triangulation {
+    // the node every half edge points to
+    target: Vec<u32>,
+    // the next half edge of the face to the left of every half edge
+    next: Vec<u32>,
+    // a half edge leaving every node
+    out: Vec<u32>,
+    degree: Vec<u32>,
+}
+
+impl Triangulation {
+    // Starts with a K4 and repeatedly selects a random edge and one of its nodes, merges up to
+    // three faces around the node and inserts a new node into the merged face. Removed edges
+    // are reused, so every edge slot stays active and a random edge is found in constant time.
+    fn generate(n: usize, seed: Option<u64>) -> Triangulation {
+        assert!(6 * n < u32::MAX as usize, "too many nodes");
+        #[cfg(feature = "debug_graph_generation")]
+        let mut counter = 0;
+        let mut graph = Triangulation::k4(n);
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        for _ in 4..n {
+            let half_edge = rng.gen_range(0..graph.target.len());
+            let typ = if graph.degree[graph.source(half_edge)] == 3 {
+                rng.gen_range(3..5)
+            } else {
+                rng.gen_range(3..6)
+            };
+
+            graph.insert(half_edge, typ);
+            #[cfg(feature = "debug_graph_generation")]
+            debug_graph(
+                &graph.to_list_graph(),
+                graph.source(half_edge),
+                graph.out.len() - 1,
+                half_edge / 2,
+                &mut counter,
+            );
+        }
+
+        graph
+    }
+
+    fn k4(capacity: usize) -> Triangulation {
+        let mut graph = Triangulation {
+            target: Vec::with_capacity(6 * capacity),
+            next: Vec::with_capacity(6 * capacity),
+            out: Vec::with_capacity(capacity),
+            degree: Vec::with_capacity(capacity),
+        };
+        let edges = [(0, 1), (1, 2), (0, 2), (2, 3), (0, 3), (1, 3)];
+        let faces = [[0, 1, 2], [0, 2, 3], [0, 3, 1], [1, 3, 2]];
+        let half_edge = |from: u32, to: u32| {
+            edges
+                .iter()
+                .enumerate()
+                .find_map(|(e, &edge)| {
+                    if edge == (from, to) {
+                        Some(2 * e as u32)
+                    } else if edge == (to, from) {
+                        Some(2 * e as u32 + 1)
+                    } else {
+                        None
+                    }
+                })
+                .unwrap()
+        };
+
+        for (from, to) in edges {
+            graph.target.extend([to, from]);
+        }
+        graph.next.resize(graph.target.len(), 0);
+        for face in faces {
+            for i in 0..3 {
+                let (a, b, c) = (face[i], face[(i + 1) % 3], face[(i + 2) % 3]);
+                graph.next[half_edge(a, b) as usize] = half_edge(b, c);
+            }
+        }
+        graph.out.extend([0, 1, 3, 7]);
+        graph.degree.extend([3; 4]);
+
+        graph
+    }
+
+    fn source(&self, half_edge: usize) -> usize {
+        self.target[half_edge ^ 1] as usize
+    }
+
+    fn edge(&self, edge: usize) -> (NodeId, NodeId) {
+        (self.source(2 * edge), self.target[2 * edge] as usize)
+    }
+
+    // Returns the half edge following the given one counterclockwise around its source
+    fn rotate(&self, half_edge: usize) -> usize {
+        self.next[self.next[half_edge] as usize] as usize ^ 1
+    }
+
+    // Removes the `typ - 3` edges following the given half edge around its source and connects
+    // a new node to all nodes of the merged face
+    fn insert(&mut self, half_edge: usize, typ: usize) {
+        let v = self.source(half_edge);
+        let u = self.out.len() as u32;
+        let mut spokes = vec![half_edge];
+        for _ in 0..typ - 2 {
+            spokes.push(self.rotate(spokes[spokes.len() - 1]));
+        }
+
+        // the boundary of the merged face, starting at v
+        let mut boundary = vec![half_edge];
+        boundary.extend(spokes[..typ - 2].iter().map(|&h| self.next[h] as usize));
+        boundary.push(spokes[typ - 2] ^ 1);
+        let nodes = boundary.iter().map(|&h| self.source(h)).collect::<Vec<_>>();
+
+        let mut edges = spokes[1..typ - 2]
+            .iter()
+            .map(|&h| h / 2)
+            .collect::<Vec<_>>();
+        for &h in &spokes[1..typ - 2] {
+            self.degree[self.target[h] as usize] -= 1;
+        }
+        for _ in 0..3 {
+            edges.push(self.target.len() / 2);
+            self.target.extend([0, 0]);
+            self.next.extend([0, 0]);
+        }
+
+        // the half edge 2e leads from u to the j-th node, 2e + 1 back to u
+        for j in 0..typ {
+            let (out, back) = (2 * edges[j], 2 * edges[(j + 1) % typ] + 1);
+            self.target[out] = nodes[j] as u32;
+            self.target[2 * edges[j] + 1] = u;
+            self.next[boundary[j]] = back as u32;
+            self.next[back] = out as u32;
+            self.next[out] = boundary[j] as u32;
+            self.out[nodes[j]] = 2 * edges[j] as u32 + 1;
+            self.degree[nodes[j]] += 1;
+        }
+        self.degree[v] -= typ as u32 - 3;
+        self.out.push(2 * edges[0] as u32);
+        self.degree.push(typ as u32);
+    }
+
+    fn to_list_graph(&self) -> ListGraph {
+        let edges = (0..self.target.len() / 2)
+            .map(|edge| self.edge(edge))
+            .collect();
+        let rotations = self
+            .out
+            .iter()
+            .map(|&first| {
+                let mut rotation = vec![first as usize / 2];
+                let mut half_edge = self.rotate(first as usize);
+                while half_edge != first as usize {
+                    rotation.push(half_edge / 2);
+                    half_edge = self.rotate(half_edge);
+                }
+                rotation
+            })
+            .collect();
+
+        ListGraph::from_rotation_system(edges, rotations)
+    }
+}
+
+/// Returns a random connected planar graph with `n` nodes and `m` edges.
+///
+/// The graph is created by removing random edges from a graph generated by [generate], which
+/// are not part of a random spanning tree. `n` has to be at least 4, since [generate] starts
+/// with a K4, and `m` has to be between `n - 1` and `3n - 6`.
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+pub fn generate_with_edges(n: usize, m: usize, seed: Option<u64>) -> ListGraph {
+    assert!(n >= 4, "the number of nodes has to be at least 4");
+    assert!(
+        n - 1 <= m && m <= 3 * n - 6,
+        "the number of edges has to be between n - 1 and 3n - 6"
+    );
     let mut rng = match seed {
         Some(seed) => StdRng::seed_from_u64(seed),
         None => StdRng::from_entropy(),
     };
+    let mut graph = generate(n, Some(rng.gen()));
+    let mut edges = graph.edge_indexes().collect::<Vec<_>>();
+    let mut representative = (0..n).collect::<Vec<_>>();
+    let mut removable = vec![];
 
-    for edge in graph.edge_indexes() {
-        urn.push(edge);
-        active[edge] = true;
-    }
+    edges.shuffle(&mut rng);
 
-    while n > 4 {
-        let mut edge;
-        while {
-            edge = *urn.choose(&mut rng).unwrap();
-            urn.remove(urn.iter().position(|e| &edge == e).unwrap());
-            !active[edge]
-        } {}
-        urn.push(edge);
+    for edge in edges {
+        let (from, to) = graph.edge(edge).unwrap();
+        let (from, to) = (
+            find(&mut representative, from),
+            find(&mut representative, to),
+        );
 
-        let (v_a, v_b) = graph.edge(edge).unwrap();
-        let vertex = if rng.gen_bool(0.5) { v_a } else { v_b };
-        let typ = *if graph.edges(vertex).unwrap().len() == 3 {
-            [3, 4].choose(&mut rng)
+        if from == to {
+            removable.push(edge);
         } else {
-            [3, 4, 5].choose(&mut rng)
+            representative[from] = to;
         }
-        .unwrap();
-        if typ >= 4 {
-            let succ_edge = graph.cyclic_incident_succ(edge, vertex).unwrap();
-            graph.remove_edge(succ_edge);
-            active[succ_edge] = false;
-        }
-        if typ == 5 {
-            let succ_edge = graph.cyclic_incident_succ(edge, vertex).unwrap();
-            graph.remove_edge(succ_edge);
-            active[succ_edge] = false;
+    }
+
+    for edge in removable.into_iter().take(3 * n - 6 - m) {
+        graph.remove_edge(edge);
+    }
+
+    graph
+}
+
+// returns the representative of the set containing `v` and halves the path to it
+fn find(representative: &mut [NodeId], mut v: NodeId) -> NodeId {
+    while representative[v] != v {
+        representative[v] = representative[representative[v]];
+        v = representative[v];
+    }
+    v
+}
+
+/// Returns a random planar graph with `n` nodes and diameter `d`.
+///
+/// The nodes are split into `d + 1` layers, the first and the last one containing a single node.
+/// The other layers are paths and neighboring layers are connected by a random triangulation
+/// of the strip between them. So the first and the last node have distance `d` while every
+/// other pair of nodes is connected through one of them by a path of length at most `d`.
+/// For `d = 1` the result is the complete graph on `n <= 4` nodes.
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+pub fn generate_with_diameter(n: usize, d: usize, seed: Option<u64>) -> ListGraph {
+    assert!(d > 0, "the diameter has to be positive");
+    assert!(n > d, "every layer requires at least one node");
+    assert!(
+        d > 1 || n <= 4,
+        "a planar graph with diameter 1 has at most 4 nodes"
+    );
+    match (n, d) {
+        (3, 1) => return ListGraph::from_faces(&[vec![0, 1, 2], vec![0, 2, 1]]),
+        (4, 1) => {
+            return ListGraph::from_faces(&[
+                vec![0, 1, 2],
+                vec![0, 2, 3],
+                vec![0, 3, 1],
+                vec![1, 3, 2],
+            ])
         }
+        _ => {}
+    }
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
 
-        let new_vertex = graph.new_vertex();
-        let mut current_edge = edge;
-        let mut current_vertex = vertex;
-        while {
-            let new_edge = graph.add_edge(new_vertex, current_vertex, None, Some(current_edge));
-            #[cfg(feature = "debug_graph_generation")]
-            debug_graph(
-                &graph,
-                vertex,
-                current_vertex,
-                new_vertex,
-                edge,
-                current_edge,
-                new_edge,
-                &mut counter,
-            );
-            active[new_edge] = true;
-            urn.push(new_edge);
+    let mut sizes = vec![1; d + 1];
+    for _ in 0..n - d - 1 {
+        sizes[rng.gen_range(1..d)] += 1;
+    }
 
-            current_vertex = graph.opposite(current_vertex, current_edge).unwrap();
-            current_edge = graph
-                .cyclic_incident_prev(current_edge, current_vertex)
-                .unwrap();
-            current_vertex != vertex
-        } {}
-        n -= 1;
+    let mut layers: Vec<Vec<NodeId>> = vec![];
+    for size in sizes {
+        let first = layers.iter().map(|layer| layer.len()).sum::<usize>();
+        layers.push((first..first + size).collect());
     }
 
-    graph
+    // the outer face runs along the first nodes of the layers and back along the last ones
+    let mut outer_face = layers.iter().map(|layer| layer[0]).collect::<Vec<_>>();
+    outer_face.extend(
+        layers[1..d]
+            .iter()
+            .rev()
+            .map(|layer| layer[layer.len() - 1]),
+    );
+    let mut faces = vec![outer_face];
+
+    for pair in layers.windows(2) {
+        faces.extend(zip_layers(&pair[0], &pair[1], false, &mut rng));
+    }
+
+    ListGraph::from_faces(&faces)
 }
 
 #[cfg(feature = "debug_graph_generation")]
-use crate::data_structure::list_graph::{EdgeId, NodeId};
+use crate::data_structure::list_graph::EdgeId;
 #[cfg(feature = "debug_graph_generation")]
-#[allow(clippy::too_many_arguments)]
 fn debug_graph(
     graph: &ListGraph,
     vertex: NodeId,
-    current_vertex: NodeId,
-    new_vertex: EdgeId,
+    new_vertex: NodeId,
     edge: EdgeId,
-    current_edge: EdgeId,
-    new_edge: EdgeId,
     counter: &mut usize,
 ) {
     let mut node_color = std::collections::HashMap::new();
     let mut edge_color = std::collections::HashMap::new();
     node_color.insert(vertex, "green".to_string());
-    node_color.insert(current_vertex, "blue".to_string());
     node_color.insert(new_vertex, "red".to_string());
     edge_color.insert(edge, "green".to_string());
-    edge_color.insert(current_edge, "blue".to_string());
-    edge_color.insert(new_edge, "red".to_string());
     crate::debug::list_graph::write_as_files(graph, &node_color, &edge_color, counter);
 }
 
 #[cfg(test)]
 mod tests {
-    use super::generate;
+    use super::{
+        generate, generate_edges, generate_link_graph, generate_with_diameter, generate_with_edges,
+    };
+    use crate::algorithm::tree_decomposition::separator_tree_decomposition;
+    use crate::data_structure::graph_dcel::GraphDCEL;
+    use crate::utils::convert::to_hash_map_graph;
+    use petgraph::algo::{dijkstra, kosaraju_scc};
 
     #[test]
     fn test_graph_generation_base() {
@@ -159,4 +410,98 @@ mod tests {
 
         assert_eq!(graph.node_indexes().count(), 100);
     }
+
+    #[test]
+    fn test_graph_generation_embedding() {
+        for n in [4, 5, 10, 200] {
+            let link_graph = generate(n, Some(n as u64)).to_link_graph();
+
+            link_graph.validate();
+            assert_eq!(link_graph.vertex_count(), n);
+            assert_eq!(link_graph.edge_count(), 3 * n - 6);
+            assert_eq!(link_graph.face_count(), 2 * n - 4);
+        }
+    }
+
+    #[test]
+    fn test_graph_generation_link_graph() {
+        let graph = generate_link_graph(100, Some(4));
+        let td = separator_tree_decomposition(&graph);
+
+        graph.validate();
+        assert_eq!(graph.vertex_count(), 100);
+        assert_eq!(graph.face_count(), 2 * 100 - 4);
+        assert_eq!(graph.vertex_by_id(99).unwrap().get_id(), 99);
+        assert!(td.verify(&to_hash_map_graph(&graph.to_pet_graph())).is_ok());
+    }
+
+    #[test]
+    fn test_graph_generation_huge() {
+        let n = 200_000;
+        let mut degrees = vec![0; n];
+
+        for (from, to) in generate_edges(n, Some(1)) {
+            degrees[from] += 1;
+            degrees[to] += 1;
+        }
+
+        assert!(degrees.iter().all(|&degree| degree >= 3));
+        assert_eq!(degrees.iter().sum::<usize>(), 6 * n - 12);
+    }
+
+    #[test]
+    fn test_graph_generation_seeded() {
+        let a = generate(500, Some(9));
+        let b = generate(500, Some(9));
+
+        assert_eq!(a.all_edges(), b.all_edges());
+    }
+
+    #[test]
+    fn test_graph_generation_with_edges() {
+        for (n, m) in [(4, 3), (10, 12), (50, 49), (50, 100), (100, 294)] {
+            let graph = generate_with_edges(n, m, Some(m as u64));
+            let link_graph = graph.to_link_graph();
+            let pet_graph = graph.to_pet_graph();
+
+            assert_eq!(pet_graph.node_count(), n);
+            assert_eq!(pet_graph.edge_count(), m);
+            assert_eq!(kosaraju_scc(&pet_graph).len(), 1);
+            assert_eq!(link_graph.face_count(), m + 2 - n);
+            link_graph.validate();
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of nodes has to be at least 4")]
+    fn test_graph_generation_with_edges_empty() {
+        generate_with_edges(0, 0, Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of nodes has to be at least 4")]
+    fn test_graph_generation_with_edges_triangle() {
+        generate_with_edges(3, 3, Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "a planar graph with diameter 1 has at most 4 nodes")]
+    fn test_graph_generation_with_diameter_k5() {
+        generate_with_diameter(5, 1, Some(1));
+    }
+
+    #[test]
+    fn test_graph_generation_with_diameter() {
+        for (n, d) in [(2, 1), (3, 1), (4, 1), (5, 2), (10, 9), (50, 5), (100, 20)] {
+            let graph = generate_with_diameter(n, d, Some(n as u64)).to_pet_graph();
+            let diameter = graph
+                .node_indices()
+                .flat_map(|v| dijkstra(&graph, v, None, |_| 1).into_values())
+                .max()
+                .unwrap();
+
+            assert_eq!(graph.node_count(), n);
+            assert_eq!(diameter, d);
+        }
+    }
 }
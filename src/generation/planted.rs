@@ -0,0 +1,136 @@
+//! Contains algorithms to generate random planar graphs with a planted optimal solution.
+//!
+//! The nodes of the graph are partitioned into cliques of at most three nodes and one node of
+//! every clique is part of the planted independent set. Since an independent set contains at
+//! most one node of every clique, the planted independent set is maximum and its complement is
+//! a minimum vertex cover.
+//!
+//! ```rust
+//! use graph_algo_ptas::algorithm::dynamic_programming::solve::DpProblem;
+//! use graph_algo_ptas::algorithm::ptas::ptas;
+//! use graph_algo_ptas::generation::planted::generate_with_independent_set;
+//!
+//! let (graph, planted) = generate_with_independent_set(100, Some(1));
+//! let sol = ptas(&graph.to_pet_graph(), &DpProblem::max_independent_set(), 0.5);
+//! assert!(sol.len() <= planted.len());
+//! ```
+
+use crate::data_structure::list_graph::{ListGraph, NodeId};
+use crate::generation::planar::generate;
+use rand::rngs::StdRng;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use std::collections::HashSet;
+
+/// Returns a random planar graph with `n` nodes together with a maximum independent set of it.
+///
+/// The nodes of a graph generated by [generate] are greedily partitioned into triangles, edges
+/// and single nodes. A random node of every part is selected and the edges between selected
+/// nodes are removed.
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+pub fn generate_with_independent_set(n: usize, seed: Option<u64>) -> (ListGraph, HashSet<NodeId>) {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let mut graph = generate(n, Some(rng.gen()));
+    let mut assigned = vec![false; n];
+    let mut order = (0..n).collect::<Vec<_>>();
+    let mut planted = HashSet::new();
+
+    order.shuffle(&mut rng);
+
+    for v in order {
+        if assigned[v] {
+            continue;
+        }
+
+        let neighbors = graph.neighbors(v).unwrap();
+        let free = neighbors
+            .iter()
+            .copied()
+            .filter(|&u| !assigned[u])
+            .collect::<Vec<_>>();
+        let triangle = free.iter().find_map(|&u| {
+            free.iter()
+                .find(|&&w| graph.neighbors(u).unwrap().contains(&w))
+                .map(|&w| (u, w))
+        });
+
+        let clique = match (triangle, free.first()) {
+            (Some((u, w)), _) => vec![v, u, w],
+            (None, Some(&u)) => vec![v, u],
+            (None, None) => vec![v],
+        };
+
+        for &u in &clique {
+            assigned[u] = true;
+        }
+        planted.insert(*clique.choose(&mut rng).unwrap());
+    }
+
+    for edge in graph.edge_indexes().collect::<Vec<_>>() {
+        let (u, v) = graph.edge(edge).unwrap();
+
+        if planted.contains(&u) && planted.contains(&v) {
+            graph.remove_edge(edge);
+        }
+    }
+
+    (graph, planted)
+}
+
+/// Returns a random planar graph with `n` nodes together with a minimum vertex cover of it,
+/// which is the complement of the maximum independent set planted by
+/// [generate_with_independent_set].
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+pub fn generate_with_vertex_cover(n: usize, seed: Option<u64>) -> (ListGraph, HashSet<NodeId>) {
+    let (graph, planted) = generate_with_independent_set(n, seed);
+    let cover = (0..n).filter(|v| !planted.contains(v)).collect();
+
+    (graph, cover)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_with_independent_set, generate_with_vertex_cover};
+    use crate::algorithm::{dynamic_programming::solve::DpProblem, ptas::ptas};
+    use crate::utils::{
+        convert::list_graph_to_hash_map_graph,
+        max_independent_set::{brute_force_max_independent_set, is_independent_set},
+        min_vertex_cover::{brute_force_min_vertex_cover, is_vertex_cover},
+    };
+
+    #[test]
+    fn optimal() {
+        for seed in 0..10 {
+            let (graph, planted) = generate_with_independent_set(14, Some(seed));
+            let graph = list_graph_to_hash_map_graph(&graph);
+
+            assert!(is_independent_set(&graph, &planted));
+            assert_eq!(brute_force_max_independent_set(&graph).len(), planted.len());
+
+            let (graph, cover) = generate_with_vertex_cover(14, Some(seed));
+            let graph = list_graph_to_hash_map_graph(&graph);
+
+            assert!(is_vertex_cover(&graph, &cover));
+            assert_eq!(brute_force_min_vertex_cover(&graph).len(), cover.len());
+        }
+    }
+
+    #[test]
+    fn ptas_bound() {
+        let (graph, planted) = generate_with_independent_set(80, Some(3));
+        let sol = ptas(
+            &graph.to_pet_graph(),
+            &DpProblem::max_independent_set(),
+            0.5,
+        );
+
+        assert!(is_independent_set(
+            &list_graph_to_hash_map_graph(&graph),
+            &sol
+        ));
+        assert!(sol.len() <= planted.len());
+        assert!(2 * sol.len() >= planted.len());
+    }
+}
@@ -0,0 +1,248 @@
+//! Contains an algorithm to sample uniformly random maximal planar graphs derived from
+//! [Optimal coding and sampling of triangulations](https://doi.org/10.1007/s00453-006-0114-8)
+//! by Poulalhon and Schaeffer.
+//!
+//! A plane tree with `n - 2` nodes, each carrying two stems, is sampled uniformly and closed
+//! into a triangulation by turning every stem followed by two sides of the contour into the
+//! third side of a triangle.
+//! Every rooted simple triangulation with `n` nodes is obtained with the same probability.
+//!
+//! ```rust
+//! use graph_algo_ptas::generation::uniform_planar::generate;
+//!
+//! let graph = generate(100, Some(1)).to_pet_graph();
+//! assert_eq!(graph.node_count(), 100);
+//! assert_eq!(graph.edge_count(), 3 * 100 - 6);
+//! ```
+
+use crate::data_structure::list_graph::{ListGraph, NodeId};
+use rand::rngs::StdRng;
+use rand::{seq::index::sample, SeedableRng};
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Clone, Copy, Debug)]
+enum Item {
+    Stem(NodeId),
+    Side(NodeId, NodeId),
+}
+
+/// Returns a uniformly random maximal planar graph with `n` nodes.
+/// If `seed` is not `None` the rng is seeded with the value of `seed`.
+pub fn generate(n: usize, seed: Option<u64>) -> ListGraph {
+    assert!(n >= 3, "a triangulation requires at least three nodes");
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let m = n - 2;
+
+    // the children of every node are distributed over the three gaps around its stems, so a
+    // uniform distribution of m - 1 children over 3m gaps gives a uniform tree up to rotation
+    let children = sample(&mut rng, 4 * m - 2, m - 1)
+        .into_iter()
+        .collect::<HashSet<_>>();
+    let mut gaps = vec![0; 3 * m];
+    let mut gap = 0;
+    for position in 0..4 * m - 2 {
+        if children.contains(&position) {
+            gaps[gap] += 1;
+        } else {
+            gap += 1;
+        }
+    }
+
+    let tree = build_tree(&gaps);
+    let mut faces = vec![];
+    let word = close(contour(&tree), &mut faces);
+    complete(&word, m, m + 1, &mut faces);
+
+    ListGraph::from_faces(&faces)
+}
+
+// Returns the items around every node of the tree given by the number of children in the gaps
+// around the stems of every node, `None` denoting a stem. The nodes are rotated by the cycle
+// lemma so that their sequence is the preorder of a tree.
+fn build_tree(gaps: &[usize]) -> Vec<Vec<Option<NodeId>>> {
+    let m = gaps.len() / 3;
+    let degree = |node: usize| gaps[3 * node] + gaps[3 * node + 1] + gaps[3 * node + 2];
+
+    let (mut sum, mut min, mut first) = (0, 0, 0);
+    for node in 0..m {
+        if sum < min {
+            (min, first) = (sum, node);
+        }
+        sum += degree(node) as isize - 1;
+    }
+
+    let mut children = vec![vec![]; m];
+    let mut open: Vec<(NodeId, usize)> = vec![];
+
+    for v in 0..m {
+        if let Some((parent, remaining)) = open.last_mut() {
+            children[*parent].push(v);
+            *remaining -= 1;
+            if *remaining == 0 {
+                open.pop();
+            }
+        }
+        if degree((first + v) % m) > 0 {
+            open.push((v, degree((first + v) % m)));
+        }
+    }
+
+    children
+        .into_iter()
+        .enumerate()
+        .map(|(v, children)| {
+            let node = (first + v) % m;
+            let (a, b) = (gaps[3 * node], gaps[3 * node] + gaps[3 * node + 1]);
+            let mut items = children[..a].iter().copied().map(Some).collect::<Vec<_>>();
+            items.push(None);
+            items.extend(children[a..b].iter().copied().map(Some));
+            items.push(None);
+            items.extend(children[b..].iter().copied().map(Some));
+            items
+        })
+        .collect()
+}
+
+// Returns the stems and sides met when walking around the tree starting at its root
+fn contour(tree: &[Vec<Option<NodeId>>]) -> Vec<Item> {
+    let mut word = vec![];
+    let mut stack = vec![(0, 0)];
+
+    while let Some((v, i)) = stack.pop() {
+        match tree[v].get(i) {
+            Some(None) => {
+                word.push(Item::Stem(v));
+                stack.push((v, i + 1));
+            }
+            Some(&Some(child)) => {
+                word.push(Item::Side(v, child));
+                stack.push((v, i + 1));
+                stack.push((child, 0));
+            }
+            None => {
+                if let Some(&(parent, _)) = stack.last() {
+                    word.push(Item::Side(v, parent));
+                }
+            }
+        }
+    }
+
+    word
+}
+
+// Closes every stem followed by two sides of the cyclic contour into a triangle, which is added
+// to `faces`. Returns the remaining cyclic word.
+fn close(word: Vec<Item>, faces: &mut Vec<Vec<NodeId>>) -> VecDeque<Item> {
+    let mut reduced = VecDeque::with_capacity(word.len());
+
+    for item in word {
+        push(&mut reduced, item, faces);
+    }
+
+    // the word is cyclic, so it is rotated until no stem can be closed across its end
+    let mut unchanged = 0;
+    while unchanged < reduced.len() {
+        let item = reduced.pop_front().unwrap();
+
+        if push(&mut reduced, item, faces) {
+            unchanged = 0;
+        } else {
+            unchanged += 1;
+        }
+    }
+
+    reduced
+}
+
+// Appends the item to the word and closes all stems it completes. Returns whether a stem
+// was closed.
+fn push(word: &mut VecDeque<Item>, mut item: Item, faces: &mut Vec<Vec<NodeId>>) -> bool {
+    let mut closed = false;
+
+    while let (Item::Side(y, z), Some(&Item::Stem(x)), Some(&Item::Side(_, _))) = (
+        item,
+        word.len().checked_sub(2).and_then(|i| word.get(i)),
+        word.back(),
+    ) {
+        faces.push(vec![x, y, z]);
+        word.truncate(word.len() - 2);
+        item = Item::Side(x, z);
+        closed = true;
+    }
+
+    word.push_back(item);
+    closed
+}
+
+// Connects the stems left after the closure to the two new nodes `u` and `v`. The word
+// consists of stems each followed by at most one side, exactly two stems are followed by
+// another stem. The stems between them are attached to `u`, the others to `v`.
+fn complete(word: &VecDeque<Item>, u: NodeId, v: NodeId, faces: &mut Vec<Vec<NodeId>>) {
+    let len = word.len();
+    let is_stem = |i: usize| matches!(word[i % len], Item::Stem(_));
+    let switches = (0..len)
+        .filter(|&i| is_stem(i) && is_stem(i + 1))
+        .collect::<Vec<_>>();
+    assert_eq!(switches.len(), 2, "the closure leaves two unmatched stems");
+
+    let mut target = u;
+    for i in (switches[0] + 1..switches[0] + 1 + len).map(|i| i % len) {
+        match (word[i], word[(i + 1) % len]) {
+            (Item::Stem(x), Item::Side(_, y)) => faces.push(vec![x, y, target]),
+            (Item::Stem(x), Item::Stem(_)) => {
+                let next = if target == u { v } else { u };
+                faces.push(vec![x, next, target]);
+                target = next;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+    use crate::data_structure::graph_dcel::GraphDCEL;
+
+    #[test]
+    fn triangulation() {
+        for n in [3, 4, 5, 10, 100, 500] {
+            for seed in 0..5 {
+                let graph = generate(n, Some(seed));
+                let link_graph = graph.to_link_graph();
+                let pet_graph = graph.to_pet_graph();
+
+                link_graph.validate();
+                assert_eq!(pet_graph.node_count(), n);
+                assert_eq!(pet_graph.edge_count(), 3 * n - 6);
+                assert_eq!(link_graph.face_count(), 2 * n - 4);
+            }
+        }
+    }
+
+    #[test]
+    fn uniform() {
+        // one of the 13 rooted triangulations with 6 nodes is the octahedron
+        let octahedra = (0..2600)
+            .map(|seed| generate(6, Some(seed)).to_pet_graph())
+            .filter(|graph| {
+                graph
+                    .node_indices()
+                    .all(|v| graph.neighbors(v).count() == 4)
+            })
+            .count();
+
+        assert!((150..250).contains(&octahedra));
+    }
+
+    #[test]
+    fn seeded() {
+        let a = generate(200, Some(3));
+        let b = generate(200, Some(3));
+
+        assert_eq!(a.all_edges(), b.all_edges());
+    }
+}
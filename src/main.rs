@@ -2,12 +2,14 @@
 mod cli {
     use clap::Parser;
     use clap::Subcommand;
-    use graph_algo_ptas::algorithm::dynamic_programming::solve::DpProblem;
-    use graph_algo_ptas::algorithm::ptas::ptas;
+    use graph_algo_ptas::algorithm::dynamic_programming::solve::{DpProblem, TdStrategy};
+    use graph_algo_ptas::algorithm::outerplanarity::{min_outerplanarity_face, outerplanarity};
+    use graph_algo_ptas::algorithm::ptas::{ptas, ptas_embedded};
     use graph_algo_ptas::data_structure::dot_reader::read_graph;
     use graph_algo_ptas::data_structure::graph_dcel::GraphDCEL;
+    use graph_algo_ptas::data_structure::link_graph::LinkGraph;
     use graph_algo_ptas::embedding::{index::Embedding, maximal_planar::index::MaximalPlanar};
-    use graph_algo_ptas::generation::planar::generate;
+    use graph_algo_ptas::generation::planar::generate_link_graph;
     use petgraph::dot::{Config, Dot};
     use petgraph::stable_graph::DefaultIx;
     use petgraph::stable_graph::StableGraph;
@@ -45,14 +47,17 @@ mod cli {
         use std::fs;
 
         let cli = Cli::parse();
-        let mut generated = false;
+        let mut generated = None;
         let mut input_graph: Option<_> = None;
 
         if let Some(file) = cli.input {
             println!("[ptas] read input graph");
             let file_input = fs::read_to_string(file);
             match file_input {
-                Ok(graph_text) => input_graph = read_graph(graph_text),
+                Ok(graph_text) => match read_graph(graph_text) {
+                    Ok(graph) => input_graph = Some(graph),
+                    Err(error) => eprintln!("[ptas] Invalid input graph: {}", error),
+                },
                 Err(_) => eprintln!("[ptas] Invalid input file"),
             }
         }
@@ -66,8 +71,9 @@ mod cli {
                 return;
             }
 
-            input_graph = Some(generate(n, None).to_pet_graph());
-            generated = true;
+            let embedding = generate_link_graph(n, None);
+            input_graph = Some(embedding.to_pet_graph());
+            generated = Some(embedding);
         }
 
         if input_graph.is_none() {
@@ -94,22 +100,30 @@ mod cli {
         );
     }
 
-    fn embed_graph(graph: &StableGraph<(), (), Undirected, DefaultIx>, generated: bool) {
-        let dcel = MaximalPlanar::embed(graph.clone());
-
-        if generated {
-            print_graph(graph);
-        }
+    fn embed_graph(
+        graph: &StableGraph<(), (), Undirected, DefaultIx>,
+        generated: Option<LinkGraph>,
+    ) {
+        // generated graphs keep the embedding they were generated with
+        let dcel = match generated {
+            Some(embedding) => {
+                print_graph(graph);
+                embedding
+            }
+            None => MaximalPlanar::embed(graph.clone()),
+        };
 
         println!("[ptas] embedded input graph:");
         dcel.get_vertexes().for_each(|v| println!("{:?}", v));
         println!();
         dcel.get_darts().for_each(|d| println!("{:?}", d));
+        println!();
+        println!("[ptas] outerplanarity: {}", outerplanarity(&dcel));
     }
 
     fn run_ptas(
         graph: &StableGraph<(), (), Undirected, DefaultIx>,
-        generated: bool,
+        generated: Option<LinkGraph>,
         min_vertex_cover: bool,
     ) {
         let (prob, out_text) = if min_vertex_cover {
@@ -117,11 +131,17 @@ mod cli {
         } else {
             (DpProblem::max_independent_set(), "Maximum Independent Set")
         };
-        let sol = ptas(graph, &prob, 0.5);
-
-        if generated {
-            print_graph(graph);
-        }
+        // generated graphs are solved on their embedding with the best outer face
+        let sol = match generated {
+            Some(mut embedding) => {
+                if let Some(face) = min_outerplanarity_face(&embedding) {
+                    embedding.set_outer_face(&face);
+                }
+                print_graph(graph);
+                ptas_embedded(&embedding, &prob, 0.5, &TdStrategy::Exact).solution
+            }
+            None => ptas(graph, &prob, 0.5),
+        };
 
         println!("[ptas] {}:", out_text);
         println!("{:?}", sol);
@@ -1,5 +1,16 @@
+use crate::{
+    algorithm::separator::rotation_system,
+    data_structure::{
+        graph_dcel::{Dart, Face, GraphDCEL, Vertex},
+        list_graph::ListGraph,
+    },
+};
 use arboretum_td::graph::{HashMapGraph, MutableGraph};
 use petgraph::{stable_graph::StableGraph, visit::EdgeRef, Undirected};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
 
 pub type UndirectedGraph = StableGraph<(), (), Undirected>;
 
@@ -19,9 +30,63 @@ pub fn to_hash_map_graph(petgraph: &UndirectedGraph) -> HashMapGraph {
     hash_map_graph
 }
 
+/// Returns the graph of a doubly connected edge list, the vertices are the vertex ids.
+pub fn dcel_to_hash_map_graph<
+    V: Vertex + Eq + Hash + Clone,
+    D: Dart + Eq + Clone,
+    F: Face + Eq + Hash + Clone,
+    VI: Iterator<Item = V>,
+    DI: Iterator<Item = D>,
+    FI: Iterator<Item = F>,
+>(
+    graph: &impl GraphDCEL<V, D, F, VI, DI, FI>,
+) -> HashMapGraph {
+    induced_hash_map_graph(&rotation_system(graph), &graph.get_vertexes().collect())
+}
+
+/// Returns the subgraph induced by `vertices` of the graph given by the neighbors of every
+/// vertex, the vertices are the vertex ids.
+pub fn induced_hash_map_graph<V: Vertex + Eq + Hash>(
+    neighbors: &HashMap<V, Vec<V>>,
+    vertices: &HashSet<V>,
+) -> HashMapGraph {
+    let mut hash_map_graph = HashMapGraph::new();
+
+    for v in vertices {
+        hash_map_graph.add_vertex(v.get_id());
+    }
+
+    for v in vertices {
+        for u in neighbors[v].iter().filter(|u| vertices.contains(u)) {
+            hash_map_graph.add_edge(v.get_id(), u.get_id());
+        }
+    }
+
+    hash_map_graph
+}
+
+/// Returns the graph of a ListGraph, nodes without edges are kept.
+pub fn list_graph_to_hash_map_graph(graph: &ListGraph) -> HashMapGraph {
+    let mut hash_map_graph = HashMapGraph::new();
+
+    for v in graph.node_indexes() {
+        hash_map_graph.add_vertex(v);
+    }
+    for (u, v) in graph.all_edges() {
+        hash_map_graph.add_edge(u, v);
+    }
+
+    hash_map_graph
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::utils::convert::{to_hash_map_graph, UndirectedGraph};
+    use crate::data_structure::{
+        graph_dcel::GraphDCEL, link_graph::LinkGraph, list_graph::ListGraph,
+    };
+    use crate::utils::convert::{
+        dcel_to_hash_map_graph, list_graph_to_hash_map_graph, to_hash_map_graph, UndirectedGraph,
+    };
     use arboretum_td::graph::BaseGraph;
 
     #[test]
@@ -88,4 +153,30 @@ mod tests {
         assert!(!hash_map_graph.has_edge(t.index(), v.index()));
         assert!(!hash_map_graph.has_edge(u.index(), w.index()));
     }
+
+    #[test]
+    fn dcel() {
+        let graph = LinkGraph::from_list_graph(&ListGraph::k4());
+        let hash_map_graph = dcel_to_hash_map_graph(&graph);
+
+        assert!(hash_map_graph.order() == graph.vertex_count());
+        for v in graph.get_vertexes() {
+            for u in graph.neighbors(&v) {
+                assert!(hash_map_graph.has_edge(v.get_id(), u.get_id()));
+            }
+        }
+    }
+
+    #[test]
+    fn list_graph() {
+        let mut graph = ListGraph::k4();
+        let edge = graph.edge_indexes().next().unwrap();
+        graph.remove_edge(edge);
+        let hash_map_graph = list_graph_to_hash_map_graph(&graph);
+
+        assert!(hash_map_graph.order() == 4);
+        for (u, v) in graph.all_edges() {
+            assert!(hash_map_graph.has_edge(u, v));
+        }
+    }
 }